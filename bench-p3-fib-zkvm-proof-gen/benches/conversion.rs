@@ -0,0 +1,123 @@
+//! Benchmarks the Miden → Plonky3 trace conversion on its own, without Miden
+//! execution or proving in the measured loop.
+//!
+//! Each trace is captured once by running the Fibonacci program and cached as raw
+//! column-major `u64`s under Cargo's target tmpdir; later runs load the cached
+//! file so only the conversion is measured.
+
+use std::fs;
+use std::path::PathBuf;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use miden_assembly::Assembler;
+use miden_processor::math::Felt;
+use miden_processor::{execute, AdviceInputs, DefaultHost, ExecutionOptions, StackInputs};
+use p3_goldilocks::Goldilocks;
+use p3_trace_convertor::convert_columns;
+use winter_prover::Trace;
+
+/// Trace heights (as log2) to benchmark
+const LOG_HEIGHTS: [usize; 7] = [12, 13, 14, 15, 16, 17, 18];
+
+/// A captured Miden main trace, stored column-major
+struct CapturedTrace {
+    height: usize,
+    columns: Vec<Vec<Felt>>,
+}
+
+impl CapturedTrace {
+    /// Run the Fibonacci program long enough for Miden to pad the trace to `2^log_height` rows
+    fn capture(log_height: usize) -> Self {
+        // Each iteration costs 4 cycles; aim for ~3/4 of the target so control-flow
+        // overhead cannot push the trace into the next power of two.
+        let fib_iter = 3 << (log_height - 4);
+        let masm_code =
+            format!("begin push.0 push.1 repeat.{fib_iter} dup.1 add swap drop end end");
+        let program = Assembler::default()
+            .assemble_program(masm_code)
+            .expect("Failed to compile Miden Assembly code");
+        let mut host = DefaultHost::default();
+        let trace = execute(
+            &program,
+            StackInputs::default(),
+            AdviceInputs::default(),
+            &mut host,
+            ExecutionOptions::default(),
+        )
+        .expect("Failed to execute Miden program");
+        assert_eq!(trace.length(), 1 << log_height);
+
+        let main_segment = trace.main_segment();
+        let columns = (0..trace.main_trace_width())
+            .map(|col_idx| main_segment.get_column(col_idx).to_vec())
+            .collect();
+        Self {
+            height: trace.length(),
+            columns,
+        }
+    }
+
+    /// Load the trace from the cache, capturing and storing it on first use
+    fn load(log_height: usize) -> Self {
+        let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+            .join(format!("miden_fib_trace_2^{log_height}.bin"));
+        if let Ok(bytes) = fs::read(&path) {
+            return Self::from_bytes(&bytes);
+        }
+
+        let captured = Self::capture(log_height);
+        fs::write(&path, captured.to_bytes()).expect("Failed to cache captured trace");
+        captured
+    }
+
+    /// Header `[height, width]` followed by every column, all as little-endian `u64`s
+    fn to_bytes(&self) -> Vec<u8> {
+        let header = [self.height as u64, self.columns.len() as u64];
+        header
+            .into_iter()
+            .chain(self.columns.iter().flatten().map(|felt| felt.as_int()))
+            .flat_map(u64::to_le_bytes)
+            .collect()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let mut words = bytes
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()));
+        let height = words.next().expect("missing trace height") as usize;
+        let width = words.next().expect("missing trace width") as usize;
+        let values: Vec<Felt> = words.map(Felt::new).collect();
+        assert_eq!(values.len(), height * width, "truncated trace cache");
+
+        Self {
+            height,
+            columns: values.chunks_exact(height).map(<[Felt]>::to_vec).collect(),
+        }
+    }
+
+    fn size_in_bytes(&self) -> u64 {
+        (self.height * self.columns.len() * size_of::<u64>()) as u64
+    }
+}
+
+fn bench_conversion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("miden_to_plonky3_conversion");
+    group.sample_size(10);
+
+    for log_height in LOG_HEIGHTS {
+        let captured = CapturedTrace::load(log_height);
+        let columns: Vec<&[Felt]> = captured.columns.iter().map(Vec::as_slice).collect();
+
+        group.throughput(Throughput::Bytes(captured.size_in_bytes()));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("2^{log_height}")),
+            &columns,
+            |b, columns| b.iter(|| convert_columns::<Goldilocks>(columns, captured.height)),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_conversion);
+criterion_main!(benches);
@@ -0,0 +1,101 @@
+//! Compares repeated conversions of one Miden trace with a fresh matrix each time
+//! (`TraceConverter::convert`) against reusing a single buffer
+//! (`TraceConverter::convert_into`).
+//!
+//! A counting global allocator reports how many allocations each approach makes
+//! over `CONVERSIONS` conversions before the timed runs start.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use miden_assembly::Assembler;
+use miden_processor::StackInputs;
+use miden_processor::{execute, AdviceInputs, DefaultHost, ExecutionOptions, ExecutionTrace};
+use p3_goldilocks::Goldilocks;
+use p3_trace_convertor::TraceConverter;
+
+/// Conversions per measurement, as in a proving loop
+const CONVERSIONS: usize = 16;
+
+/// Fibonacci iterations; gives a 2^12-row trace
+const FIB_ITER: usize = 768;
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn fib_trace() -> ExecutionTrace {
+    let masm_code = format!("begin push.0 push.1 repeat.{FIB_ITER} dup.1 add swap drop end end");
+    let program = Assembler::default()
+        .assemble_program(masm_code)
+        .expect("Failed to compile Miden Assembly code");
+    execute(
+        &program,
+        StackInputs::default(),
+        AdviceInputs::default(),
+        &mut DefaultHost::default(),
+        ExecutionOptions::default(),
+    )
+    .expect("Failed to execute Miden program")
+}
+
+fn convert_fresh(trace: &ExecutionTrace) {
+    for _ in 0..CONVERSIONS {
+        black_box(TraceConverter::convert::<Goldilocks>(trace).unwrap());
+    }
+}
+
+fn convert_reused(trace: &ExecutionTrace, buf: &mut Vec<Goldilocks>) {
+    for _ in 0..CONVERSIONS {
+        let matrix = TraceConverter::convert_into(trace, buf).unwrap();
+        *buf = black_box(matrix).values;
+    }
+}
+
+/// Allocations made by `f`
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn bench_reuse_buffer(c: &mut Criterion) {
+    let trace = fib_trace();
+    let mut buf = Vec::new();
+
+    let fresh = count_allocations(|| convert_fresh(&trace));
+    let reused = count_allocations(|| convert_reused(&trace, &mut buf));
+    println!("allocations over {CONVERSIONS} conversions: convert {fresh}, convert_into {reused}");
+
+    let mut group = c.benchmark_group("repeated_conversion");
+    group.sample_size(10);
+    group.bench_function("convert", |b| b.iter(|| convert_fresh(&trace)));
+    group.bench_function("convert_into", |b| {
+        b.iter(|| convert_reused(&trace, &mut buf))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_reuse_buffer);
+criterion_main!(benches);
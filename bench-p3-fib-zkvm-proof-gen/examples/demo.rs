@@ -1,13 +1,13 @@
 //! Demo of the new fib2 APIs
 //!
 //! This example demonstrates the new refactored API:
-//! - trace_gen(fib_iter: usize) -> (MidenTrace, P3Trace)
-//! - p3_generate_proof(p3_trace)  
+//! - trace_gen(fib_iter: usize, log_dir) -> (MidenTrace, P3Trace)
+//! - p3_generate_proof_blake3(p3_trace) -> Proof
 //! - miden_generate_proof(miden_trace)
 
 use std::env;
 
-use fib_zkvm::{miden_generate_proof, p3_generate_proof_blake3, trace_gen};
+use fib_zkvm::{attest_same_trace, miden_generate_proof, p3_generate_proof_blake3, trace_gen};
 use p3_matrix::Matrix;
 use winter_prover::Trace;
 
@@ -44,7 +44,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     for log_iter in 16..17 {
         let iteration = base << log_iter;
         println!("\n🔐 Generating proof from Plonky3 trace...");
-        let (miden_trace, p3_trace, program, stack_inputs, advice_inputs) = trace_gen(iteration)?;
+        let (miden_trace, p3_trace, program, stack_inputs, advice_inputs) =
+            trace_gen(iteration, ".")?;
+        if !attest_same_trace(&miden_trace, &p3_trace) {
+            return Err("P3 trace does not match the Miden execution".into());
+        }
+        println!("   ✅ P3 and Miden traces attest to the same execution");
         println!(
             "========================\n   Using P3 trace ({}×{}) for proof generation...\n========================",
             p3_trace.height(),
@@ -53,7 +58,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         tracing::info!("\n🔐 P3 with Blake3.");
         match p3_generate_proof_blake3(p3_trace.clone()) {
-            Ok(()) => tracing::info!("   ✅ P3 Blake3 proof generation successful!"),
+            Ok(proof) => {
+                tracing::info!("   ✅ P3 Blake3 proof generation successful!");
+                let proof_filename = format!("fib_{}_proof_p3.bin", iteration);
+                let bytes = postcard::to_allocvec(&proof)?;
+                std::fs::write(&proof_filename, &bytes)?;
+                tracing::info!(
+                    "   • Proof ({} bytes) written to {}",
+                    bytes.len(),
+                    proof_filename
+                );
+            }
             Err(e) => tracing::info!("   ❌ P3 Blake3 proof generation failed: {}", e),
         }
 
@@ -70,7 +85,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             advice_inputs.clone(),
             miden_vm::HashFunction::Blake3_256,
         ) {
-            Ok(()) => println!("   ✅ Miden proof generation successful!"),
+            Ok(_) => println!("   ✅ Miden proof generation successful!"),
             Err(e) => println!("   ❌ Miden proof generation failed: {}", e),
         }
     }
@@ -0,0 +1,84 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use miden_vm::HashFunction;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_uni_stark::verify;
+
+use crate::{
+    create_blake3_config, miden_generate_proof, p3_generate_proof_blake3_with_air, trace_gen,
+    IncrementAir, Val,
+};
+
+/// Timings and serialized size of one proof
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofStats {
+    pub prove_time: Duration,
+    pub verify_time: Duration,
+    pub proof_bytes: usize,
+}
+
+/// Miden-native and Plonky3 proofs of the same Fibonacci execution
+///
+/// A side that fails to prove or verify holds the error message instead of stats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComparisonReport {
+    pub fib_iter: usize,
+    pub miden: Result<ProofStats, String>,
+    pub p3: Result<ProofStats, String>,
+}
+
+/// Prove the `fib_iter`-step Fibonacci program with Miden's own prover and with
+/// Plonky3 over the converted trace, both using Blake3
+///
+/// The trace logs are written to `log_dir` as by [`trace_gen`].
+pub fn compare_proof_systems(fib_iter: usize, log_dir: impl AsRef<Path>) -> ComparisonReport {
+    let (_, p3_trace, program, stack_inputs, advice_inputs) = match trace_gen(fib_iter, log_dir) {
+        Ok(output) => output,
+        Err(e) => {
+            let message = format!("Trace generation failed: {e}");
+            return ComparisonReport {
+                fib_iter,
+                miden: Err(message.clone()),
+                p3: Err(message),
+            };
+        }
+    };
+
+    let miden = miden_generate_proof(
+        &program,
+        stack_inputs,
+        advice_inputs,
+        HashFunction::Blake3_256,
+    )
+    .map_err(|e| e.to_string());
+
+    let p3 = p3_proof_stats(p3_trace).map_err(|e| e.to_string());
+
+    ComparisonReport {
+        fib_iter,
+        miden,
+        p3,
+    }
+}
+
+/// Prove and verify `p3_trace` with Blake3, timing the two steps separately
+fn p3_proof_stats(p3_trace: RowMajorMatrix<Val>) -> Result<ProofStats, Box<dyn std::error::Error>> {
+    let air = IncrementAir::for_trace(&p3_trace);
+
+    let prove_start = Instant::now();
+    let proof = p3_generate_proof_blake3_with_air(p3_trace, &air, false)?;
+    let prove_time = prove_start.elapsed();
+    let proof_bytes = postcard::to_allocvec(&proof)?.len();
+
+    let verify_start = Instant::now();
+    verify(&create_blake3_config(), &air, &proof, &vec![])
+        .map_err(|e| format!("Verification failed: {:?}", e))?;
+    let verify_time = verify_start.elapsed();
+
+    Ok(ProofStats {
+        prove_time,
+        verify_time,
+        proof_bytes,
+    })
+}
@@ -1,9 +1,14 @@
+mod compare;
+pub use compare::{compare_proof_systems, ComparisonReport, ProofStats};
+
 mod miden;
 pub use miden::*;
 
 mod plonky3;
 pub use plonky3::{
-    create_blake3_config, create_keccak_config, p3_generate_proof_blake3, p3_generate_proof_keccak,
+    create_blake3_config, create_keccak_config, create_poseidon2_config, p3_generate_proof_blake3,
+    p3_generate_proof_blake3_with_air, p3_generate_proof_keccak, p3_generate_proof_keccak_with_air,
+    p3_generate_proof_poseidon2, p3_generate_proof_poseidon2_with_air, ProvingError,
 };
 
 mod trace;
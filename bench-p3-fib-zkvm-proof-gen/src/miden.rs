@@ -6,6 +6,8 @@ use miden_prover::{prove, ProvingOptions};
 use miden_verifier::verify;
 use miden_vm::{AdviceInputs, DefaultHost, HashFunction, Program, ProgramInfo, StackInputs};
 
+use crate::ProofStats;
+
 /// Generate a STARK proof using Miden's native proving system
 ///
 /// # Arguments
@@ -14,19 +16,20 @@ use miden_vm::{AdviceInputs, DefaultHost, HashFunction, Program, ProgramInfo, St
 /// * `advice_inputs` - Advice inputs for the program
 ///
 /// # Returns
-/// * `Result<(), Box<dyn std::error::Error>>` - Success or error
+/// * `Result<ProofStats, Box<dyn std::error::Error>>` - Timings and proof size, or error
 pub fn miden_generate_proof(
     program: &Program,
     stack_inputs: StackInputs,
     advice_inputs: AdviceInputs,
     hash_fn: HashFunction,
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<ProofStats, Box<dyn std::error::Error>> {
     println!("🔐 Generating native Miden STARK proof...");
 
     // Generate proof
     let proving_options = ProvingOptions::with_128_bit_security(hash_fn);
     let mut host_for_proving = DefaultHost::default();
 
+    let prove_start = Instant::now();
     let proof_timer = start_timer!(|| "Miden STARK proof generation");
     let (stack_outputs, proof) = prove(
         program,
@@ -36,6 +39,8 @@ pub fn miden_generate_proof(
         proving_options,
     )?;
     end_timer!(proof_timer);
+    let prove_time = prove_start.elapsed();
+    let proof_bytes = proof.to_bytes().len();
 
     // Verify the proof
     println!("   🔍 Verifying Miden proof...");
@@ -43,13 +48,14 @@ pub fn miden_generate_proof(
 
     let verify_start = Instant::now();
     let verify_timer = start_timer!(|| "Miden proof verification");
-    match verify(program_info, stack_inputs, stack_outputs.clone(), proof) {
+    let verify_time = match verify(program_info, stack_inputs, stack_outputs.clone(), proof) {
         Ok(security_level) => {
             end_timer!(verify_timer);
             let verify_time = verify_start.elapsed();
             println!("   ✅ Proof verification successful!");
             println!("   ⏱️  Verification time: {:?}", verify_time);
             println!("   🔒 Security level: {} bits", security_level);
+            verify_time
         }
         Err(e) => {
             end_timer!(verify_timer);
@@ -58,8 +64,12 @@ pub fn miden_generate_proof(
             println!("   ⏱️  Verification time: {:?}", verify_time);
             return Err(format!("Miden proof verification failed: {:?}", e).into());
         }
-    }
+    };
 
     println!("   🎉 Successfully generated and verified native Miden STARK proof!");
-    Ok(())
+    Ok(ProofStats {
+        prove_time,
+        verify_time,
+        proof_bytes,
+    })
 }
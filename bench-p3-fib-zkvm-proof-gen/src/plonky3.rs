@@ -1,15 +1,54 @@
+use std::fmt;
+
+use p3_air::BaseAir;
+use p3_field::PrimeField64;
 use p3_fri::FriParameters;
 use p3_keccak::KeccakF;
 use p3_matrix::dense::RowMajorMatrix;
 use p3_matrix::Matrix;
-use p3_uni_stark::{prove, verify, StarkGenericConfig};
+use p3_uni_stark::{prove, verify, Proof, StarkGenericConfig};
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
 
 use crate::{
     Blake3ByteHash, Blake3ChallengeMmcs, Blake3Challenger, Blake3Compress, Blake3Config,
     Blake3FieldHash, Blake3Pcs, Blake3U64Hash, Blake3ValMmcs, ByteHash, ChallengeMmcs, Challenger,
-    Dft, FieldHash, IncrementAir, KeccakConfig, MyCompress, Pcs, U64Hash, Val, ValMmcs,
+    Dft, FieldHash, IncrementAir, KeccakConfig, MyCompress, Pcs, Poseidon2ChallengeMmcs,
+    Poseidon2Challenger, Poseidon2Compress, Poseidon2Config, Poseidon2Hash, Poseidon2Pcs,
+    Poseidon2Perm, Poseidon2ValMmcs, U64Hash, Val, ValMmcs,
 };
 
+/// Seed for the Poseidon2 round constants, so every run builds the same permutation
+const POSEIDON2_SEED: u64 = 42;
+
+/// Error type for the Plonky3 proving helpers
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProvingError {
+    /// The trace does not have as many columns as the AIR expects
+    WidthMismatch { trace: usize, air: usize },
+    /// Column 0 does not advance by the AIR's step at `row`, see [`IncrementAir::check`]
+    ConstraintViolation { row: usize, message: String },
+}
+
+impl fmt::Display for ProvingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProvingError::WidthMismatch { trace, air } => write!(
+                f,
+                "Trace width {} does not match the AIR width {}",
+                trace, air
+            ),
+            ProvingError::ConstraintViolation { row, message } => write!(
+                f,
+                "Trace violates the increment constraint at row {}: {}",
+                row, message
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ProvingError {}
+
 /// Create a Keccak-based configuration for Plonky3 STARK proofs
 pub fn create_keccak_config() -> KeccakConfig {
     let byte_hash = ByteHash {};
@@ -74,28 +113,108 @@ pub fn create_blake3_config() -> Blake3Config {
     Blake3Config::new(pcs, challenger)
 }
 
-/// Generate a Plonky3 STARK proof using Keccak hash function
+/// Create a Poseidon2-based configuration for Plonky3 STARK proofs
+pub fn create_poseidon2_config() -> Poseidon2Config {
+    let mut rng = SmallRng::seed_from_u64(POSEIDON2_SEED);
+    let perm = Poseidon2Perm::new_from_rng_128(&mut rng);
+    let hash = Poseidon2Hash::new(perm.clone());
+    let compress = Poseidon2Compress::new(perm.clone());
+
+    // === MERKLE TREE COMMITMENT SCHEME ===
+    let val_mmcs = Poseidon2ValMmcs::new(hash, compress);
+    let challenge_mmcs = Poseidon2ChallengeMmcs::new(val_mmcs.clone());
+
+    // === DISCRETE FOURIER TRANSFORM ===
+    let dft = Dft::default();
+
+    // === CHALLENGER (FIAT-SHAMIR) ===
+    let challenger = Poseidon2Challenger::new(perm);
+
+    // === FRI POLYNOMIAL COMMITMENT SCHEME ===
+    let fri_params = FriParameters {
+        log_blowup: 1,
+        log_final_poly_len: 0,
+        num_queries: 100,
+        proof_of_work_bits: 1,
+        mmcs: challenge_mmcs,
+    };
+
+    let pcs = Poseidon2Pcs::new(dft, val_mmcs, fri_params);
+
+    // === STARK CONFIGURATION ===
+    Poseidon2Config::new(pcs, challenger)
+}
+
+/// Generate and verify a Plonky3 STARK proof using Keccak hash function
 pub fn p3_generate_proof_keccak(
     p3_trace: RowMajorMatrix<Val>,
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<Proof<KeccakConfig>, Box<dyn std::error::Error>> {
+    let air = IncrementAir::for_trace(&p3_trace);
+    p3_generate_proof_keccak_with_air(p3_trace, &air, true)
+}
+
+/// [`p3_generate_proof_keccak`] against an [`IncrementAir`] with a custom step,
+/// verifying the proof before returning it only if `verify` is set
+pub fn p3_generate_proof_keccak_with_air(
+    p3_trace: RowMajorMatrix<Val>,
+    air: &IncrementAir,
+    verify: bool,
+) -> Result<Proof<KeccakConfig>, Box<dyn std::error::Error>> {
     let config = create_keccak_config();
-    p3_generate_proof_with_config(p3_trace, config, "Keccak")
+    p3_generate_proof_with_config(p3_trace, config, air, "Keccak", verify)
 }
 
-/// Generate a Plonky3 STARK proof using Blake3 hash function
+/// Generate and verify a Plonky3 STARK proof using Blake3 hash function
 pub fn p3_generate_proof_blake3(
     p3_trace: RowMajorMatrix<Val>,
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<Proof<Blake3Config>, Box<dyn std::error::Error>> {
+    let air = IncrementAir::for_trace(&p3_trace);
+    p3_generate_proof_blake3_with_air(p3_trace, &air, true)
+}
+
+/// [`p3_generate_proof_blake3`] against an [`IncrementAir`] with a custom step,
+/// verifying the proof before returning it only if `verify` is set
+pub fn p3_generate_proof_blake3_with_air(
+    p3_trace: RowMajorMatrix<Val>,
+    air: &IncrementAir,
+    verify: bool,
+) -> Result<Proof<Blake3Config>, Box<dyn std::error::Error>> {
     let config = create_blake3_config();
-    p3_generate_proof_with_config(p3_trace, config, "Blake3")
+    p3_generate_proof_with_config(p3_trace, config, air, "Blake3", verify)
+}
+
+/// Generate and verify a Plonky3 STARK proof using Poseidon2 hash function
+pub fn p3_generate_proof_poseidon2(
+    p3_trace: RowMajorMatrix<Val>,
+) -> Result<Proof<Poseidon2Config>, Box<dyn std::error::Error>> {
+    let air = IncrementAir::for_trace(&p3_trace);
+    p3_generate_proof_poseidon2_with_air(p3_trace, &air, true)
+}
+
+/// [`p3_generate_proof_poseidon2`] against an [`IncrementAir`] with a custom step,
+/// verifying the proof before returning it only if `verify` is set
+pub fn p3_generate_proof_poseidon2_with_air(
+    p3_trace: RowMajorMatrix<Val>,
+    air: &IncrementAir,
+    verify: bool,
+) -> Result<Proof<Poseidon2Config>, Box<dyn std::error::Error>> {
+    let config = create_poseidon2_config();
+    p3_generate_proof_with_config(p3_trace, config, air, "Poseidon2", verify)
 }
 
 /// Generic proof generation function that works with any StarkGenericConfig
+///
+/// Returns the proof so callers can serialize or measure it.
 fn p3_generate_proof_with_config<C: StarkGenericConfig>(
     p3_trace: RowMajorMatrix<p3_uni_stark::Val<C>>,
     config: C,
+    air: &IncrementAir,
     hash_name: &str,
-) -> Result<(), Box<dyn std::error::Error>> {
+    verify_proof: bool,
+) -> Result<Proof<C>, Box<dyn std::error::Error>>
+where
+    p3_uni_stark::Val<C>: PrimeField64,
+{
     tracing::info!(
         "   • P3 trace dimensions: {}×{}",
         p3_trace.height(),
@@ -104,24 +223,41 @@ fn p3_generate_proof_with_config<C: StarkGenericConfig>(
 
     // === AIR INSTANTIATION ===
     tracing::info!(
-        "\n🏗️  Using synthetic increment AIR with constraint: trace[i][0] = trace[i-1][0] + 1"
+        "\n🏗️  Using synthetic increment AIR with constraint: trace[i][0] = trace[i-1][0] + {}",
+        air.step
     );
-    let air = IncrementAir;
+
+    // Catch a mismatched trace here rather than deep inside the prover
+    let air_width = BaseAir::<p3_uni_stark::Val<C>>::width(air);
+    if p3_trace.width() != air_width {
+        return Err(ProvingError::WidthMismatch {
+            trace: p3_trace.width(),
+            air: air_width,
+        }
+        .into());
+    }
+    if let Err((row, message)) = air.check(&p3_trace) {
+        return Err(ProvingError::ConstraintViolation { row, message }.into());
+    }
 
     // === PROOF GENERATION ===
     tracing::info!("\n🔐 Generating proof with {}...", hash_name);
     let start_time = std::time::Instant::now();
 
-    let proof = prove(&config, &air, p3_trace, &vec![]);
+    let proof = prove(&config, air, p3_trace, &vec![]);
 
     let proof_time = start_time.elapsed();
     tracing::info!("   • Proof generated in {:.2}s", proof_time.as_secs_f64());
 
+    if !verify_proof {
+        return Ok(proof);
+    }
+
     // === PROOF VERIFICATION ===
     tracing::info!("\n✅ Verifying proof...");
     let start_time = std::time::Instant::now();
 
-    match verify(&config, &air, &proof, &vec![]) {
+    match verify(&config, air, &proof, &vec![]) {
         Ok(()) => {
             let verify_time = start_time.elapsed();
             tracing::info!(
@@ -139,7 +275,10 @@ fn p3_generate_proof_with_config<C: StarkGenericConfig>(
         "\n🎉 Successfully proved the increment constraint using Plonky3 with {}!",
         hash_name
     );
-    tracing::info!("   • Constraint: trace[i][0] = trace[i-1][0] + 1 for all transitions");
+    tracing::info!(
+        "   • Constraint: trace[i][0] = trace[i-1][0] + {} for all transitions",
+        air.step
+    );
 
-    Ok(())
+    Ok(proof)
 }
@@ -1,7 +1,20 @@
+use p3_air::BaseAir;
+use p3_matrix::dense::RowMajorMatrix;
 use p3_matrix::Matrix;
+use p3_uni_stark::{prove, verify};
 use winter_prover::Trace;
 
-use crate::trace_gen;
+use p3_field::PrimeCharacteristicRing;
+use p3_goldilocks::Goldilocks;
+use p3_trace_convertor::layout::IS_PADDING_COL;
+
+use crate::trace::assert_traces_equivalent;
+use crate::{
+    attest_same_trace, compare_proof_systems, create_keccak_config, create_poseidon2_config,
+    execute_masm, p3_generate_proof_blake3, p3_generate_proof_keccak,
+    p3_generate_proof_keccak_with_air, p3_generate_proof_poseidon2, read_trace_binary, trace_gen,
+    write_trace_binary, IncrementAir, ProvingError, Val, NUM_COLS,
+};
 
 /// Test that we can successfully generate traces using the new API
 /// This test verifies:
@@ -11,7 +24,7 @@ use crate::trace_gen;
 /// 4. Power-of-2 padding is applied correctly
 #[test]
 fn test_trace_gen() {
-    match trace_gen(10) {
+    match trace_gen(10, std::env::temp_dir()) {
         Ok((miden_trace, p3_trace, _program, _stack_inputs, _advice_inputs)) => {
             // Verify basic properties of both traces
             assert!(miden_trace.length() > 0, "Miden trace should have rows");
@@ -39,3 +52,249 @@ fn test_trace_gen() {
         }
     }
 }
+
+#[test]
+fn test_attest_same_trace_detects_altered_matrix() {
+    let (miden_trace, p3_trace, ..) =
+        trace_gen(10, std::env::temp_dir()).expect("trace generation failed");
+    assert!(attest_same_trace(&miden_trace, &p3_trace));
+
+    // Padding rows are not part of the execution
+    let mut padded = p3_trace.clone();
+    let last = padded.values.len() - 1;
+    if miden_trace.length() < padded.height() {
+        padded.values[last] += Goldilocks::ONE;
+        assert!(attest_same_trace(&miden_trace, &padded));
+    }
+
+    let mut altered = p3_trace.clone();
+    altered.values[p3_trace.width() + 1] += Goldilocks::ONE;
+    assert!(!attest_same_trace(&miden_trace, &altered));
+}
+
+#[test]
+fn test_assert_traces_equivalent_reports_first_mismatch() {
+    let (miden_trace, p3_trace, ..) =
+        trace_gen(10, std::env::temp_dir()).expect("trace generation failed");
+    assert_eq!(assert_traces_equivalent(&miden_trace, &p3_trace), Ok(()));
+
+    let mut altered = p3_trace.clone();
+    altered.values[2 * p3_trace.width() + 5] += Goldilocks::ONE;
+    let mismatch = assert_traces_equivalent(&miden_trace, &altered).unwrap_err();
+    assert!(mismatch.starts_with("row 2, column 5:"), "{mismatch}");
+}
+
+#[test]
+fn test_execute_masm_returns_error_for_invalid_program() {
+    assert!(execute_masm("begin push.1 not_an_instruction end").is_err());
+    // Assembles, but fails at runtime
+    assert!(execute_masm("begin push.0 assert end").is_err());
+    assert!(execute_masm("begin push.1 drop end").is_ok());
+}
+
+#[test]
+fn test_increment_air_check_on_miden_trace() {
+    let (_, p3_trace, ..) = trace_gen(10, std::env::temp_dir()).expect("trace generation failed");
+    let air = IncrementAir::for_trace(&p3_trace);
+    assert_eq!(BaseAir::<Val>::width(&air), p3_trace.width());
+    assert_eq!(air.check(&p3_trace), Ok(()));
+
+    // Break the clock at row 5; the first violation is reported there
+    let mut altered = p3_trace.clone();
+    let width = altered.width();
+    altered.values[5 * width] += Goldilocks::ONE;
+    let (row, _) = IncrementAir::default().check(&altered).unwrap_err();
+    assert_eq!(row, 5);
+}
+
+#[test]
+fn test_increment_air_with_step_two() {
+    // Column 0 counts 0, 2, 4, ...; the other columns stay zero
+    let num_rows = 64;
+    let mut values = vec![Val::ZERO; num_rows * NUM_COLS];
+    for row in 0..num_rows {
+        values[row * NUM_COLS] = Val::from_usize(2 * row);
+    }
+    let trace = RowMajorMatrix::new(values, NUM_COLS);
+
+    let step_two = IncrementAir {
+        step: Val::from_u64(2),
+        ..IncrementAir::default()
+    };
+    assert_eq!(step_two.check(&trace), Ok(()));
+    assert_eq!(IncrementAir::default().check(&trace).unwrap_err().0, 1);
+
+    let config = create_keccak_config();
+    let proof = prove(&config, &step_two, trace, &vec![]);
+    verify(&config, &step_two, &proof, &vec![]).expect("step 2 trace should verify");
+    assert!(verify(&config, &IncrementAir::default(), &proof, &vec![]).is_err());
+}
+
+#[test]
+fn test_prove_trace_with_step_three() {
+    let num_rows = 32;
+    let mut values = vec![Val::ZERO; num_rows * NUM_COLS];
+    for row in 0..num_rows {
+        values[row * NUM_COLS] = Val::from_usize(3 * row);
+    }
+    let trace = RowMajorMatrix::new(values, NUM_COLS);
+
+    let step_three = IncrementAir {
+        step: Val::from_u64(3),
+        ..IncrementAir::default()
+    };
+    p3_generate_proof_keccak_with_air(trace.clone(), &step_three, true)
+        .expect("step 3 trace should prove");
+
+    // Checked against the wrong step, the trace is rejected before proving
+    let step_two = IncrementAir {
+        step: Val::from_u64(2),
+        ..IncrementAir::default()
+    };
+    let err = p3_generate_proof_keccak_with_air(trace, &step_two, true)
+        .err()
+        .expect("trace should be rejected");
+    assert!(matches!(
+        err.downcast_ref::<ProvingError>(),
+        Some(ProvingError::ConstraintViolation { row: 1, .. })
+    ));
+}
+
+/// `real_rows` rows counting up by 1, then selector-marked zero rows up to `num_rows`
+fn padded_counter_trace(real_rows: usize, num_rows: usize) -> RowMajorMatrix<Val> {
+    padded_counter_trace_with_width(real_rows, num_rows, NUM_COLS)
+}
+
+fn padded_counter_trace_with_width(
+    real_rows: usize,
+    num_rows: usize,
+    width: usize,
+) -> RowMajorMatrix<Val> {
+    let mut values = vec![Val::ZERO; num_rows * width];
+    for row in 0..num_rows {
+        if row < real_rows {
+            values[row * width] = Val::from_usize(row);
+        } else {
+            values[row * width + width - 1] = Val::ONE;
+        }
+    }
+    RowMajorMatrix::new(values, width)
+}
+
+#[test]
+fn test_padding_rows_must_be_zero() {
+    let trace = padded_counter_trace(20, 32);
+    assert_eq!(IncrementAir::default().check(&trace), Ok(()));
+    p3_generate_proof_keccak(trace.clone()).expect("zero-padded trace should prove");
+
+    let mut corrupted = trace;
+    corrupted.values[25 * NUM_COLS + 3] = Val::from_u64(7);
+    assert_eq!(IncrementAir::default().check(&corrupted).unwrap_err().0, 25);
+    let err = p3_generate_proof_keccak(corrupted)
+        .err()
+        .expect("trace should be rejected");
+    assert!(matches!(
+        err.downcast_ref::<ProvingError>(),
+        Some(ProvingError::ConstraintViolation { row: 25, .. })
+    ));
+}
+
+#[test]
+fn test_real_row_after_padding_is_rejected() {
+    let mut trace = padded_counter_trace(20, 32);
+    trace.values[31 * NUM_COLS + IS_PADDING_COL] = Val::ZERO;
+    assert_eq!(IncrementAir::default().check(&trace).unwrap_err().0, 31);
+}
+
+// Debug builds check the constraints inside `prove` and panic before a proof exists
+#[cfg(not(debug_assertions))]
+#[test]
+fn test_corrupted_padding_fails_verification() {
+    let mut trace = padded_counter_trace(20, 32);
+    trace.values[25 * NUM_COLS + 3] = Val::from_u64(7);
+
+    let config = create_keccak_config();
+    let air = IncrementAir::default();
+    let proof = prove(&config, &air, trace, &vec![]);
+    assert!(verify(&config, &air, &proof, &vec![]).is_err());
+}
+
+#[test]
+fn test_proving_rejects_trace_with_wrong_width() {
+    let trace = RowMajorMatrix::new(vec![Val::ZERO; 8 * (NUM_COLS - 1)], NUM_COLS - 1);
+
+    let err = p3_generate_proof_keccak_with_air(trace, &IncrementAir::default(), true)
+        .err()
+        .expect("trace should be rejected");
+    assert_eq!(
+        err.downcast_ref::<ProvingError>(),
+        Some(&ProvingError::WidthMismatch {
+            trace: NUM_COLS - 1,
+            air: NUM_COLS,
+        })
+    );
+}
+
+#[test]
+fn test_returned_proof_verifies_independently() {
+    let trace = padded_counter_trace(20, 32);
+    let air = IncrementAir::for_trace(&trace);
+    let proof = p3_generate_proof_keccak_with_air(trace, &air, false).expect("trace should prove");
+
+    verify(&create_keccak_config(), &air, &proof, &vec![]).expect("returned proof should verify");
+    let step_two = IncrementAir {
+        step: Val::from_u64(2),
+        ..air
+    };
+    assert!(verify(&create_keccak_config(), &step_two, &proof, &vec![]).is_err());
+}
+
+#[test]
+fn test_proving_follows_trace_width() {
+    let trace = padded_counter_trace_with_width(20, 32, 17);
+    let air = IncrementAir::for_trace(&trace);
+    assert_eq!(BaseAir::<Val>::width(&air), 17);
+    assert_eq!(air.check(&trace), Ok(()));
+    p3_generate_proof_keccak(trace.clone()).expect("17-column trace should prove");
+    p3_generate_proof_blake3(trace).expect("17-column trace should prove");
+}
+
+#[test]
+fn test_binary_trace_roundtrip() {
+    let values = (0..3 * 8).map(|i| Val::from_u64(i * 1_000_003)).collect();
+    let trace = RowMajorMatrix::new(values, 3);
+    let path = std::env::temp_dir().join(format!(
+        "fib-zkvm-trace-roundtrip-{}.bin",
+        std::process::id()
+    ));
+
+    write_trace_binary(&path, &trace).unwrap();
+    let bytes = std::fs::metadata(&path).unwrap().len();
+    let read = read_trace_binary(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(bytes, 8 * (2 + 3 * 8));
+    assert_eq!(read, trace);
+}
+
+#[test]
+fn test_compare_proof_systems() {
+    let report = compare_proof_systems(10, std::env::temp_dir());
+    assert_eq!(report.fib_iter, 10);
+
+    let miden = report.miden.expect("Miden proof should succeed");
+    let p3 = report.p3.expect("P3 proof should succeed");
+    assert!(miden.proof_bytes > 0);
+    assert!(p3.proof_bytes > 0);
+}
+
+#[test]
+fn test_prove_miden_trace_with_poseidon2() {
+    let (_, p3_trace, ..) = trace_gen(10, std::env::temp_dir()).expect("trace generation failed");
+    let air = IncrementAir::for_trace(&p3_trace);
+
+    let proof = p3_generate_proof_poseidon2(p3_trace).expect("Poseidon2 proof should succeed");
+    // The seeded permutation is the same in a fresh config
+    verify(&create_poseidon2_config(), &air, &proof, &vec![])
+        .expect("proof should verify under a fresh config");
+}
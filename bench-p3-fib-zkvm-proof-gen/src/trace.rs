@@ -1,27 +1,32 @@
 use std::fs::File;
-use std::io::Write;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
 
 use miden_assembly::Assembler;
-use miden_processor::{execute, AdviceInputs, DefaultHost, ExecutionOptions, StackInputs};
+use miden_processor::{
+    execute, AdviceInputs, DefaultHost, ExecutionOptions, ExecutionTrace, StackInputs,
+};
 use miden_vm::{AdviceInputs as VmAdviceInputs, StackInputs as VmStackInputs};
 use p3_air::{Air, AirBuilder, BaseAir};
 use p3_field::{PrimeCharacteristicRing, PrimeField64};
 use p3_goldilocks::Goldilocks;
 use p3_matrix::dense::RowMajorMatrix;
 use p3_matrix::Matrix;
-use p3_trace_convertor::{convert_miden_trace, TraceConverter};
+use p3_trace_convertor::{
+    convert_miden_execution_with_options, ConversionOptions, LastRowFixup, TraceConverter,
+};
 use winter_prover::Trace;
 
-use crate::NUM_COLS;
+use crate::{Val, NUM_COLS};
 
-/// Write Miden trace to a log file with custom filename
+/// Write Miden trace to a log file at `path`
 fn write_miden_trace_to_file(
     miden_trace: &miden_processor::ExecutionTrace,
-    filename: &str,
+    path: &Path,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    println!("   📝 Writing Miden trace to {}...", filename);
+    println!("   📝 Writing Miden trace to {}...", path.display());
 
-    let mut file = File::create(filename)?;
+    let mut file = File::create(path)?;
     let main_segment = miden_trace.main_segment();
     let height = miden_trace.length();
     let width = miden_trace.main_trace_width();
@@ -44,18 +49,18 @@ fn write_miden_trace_to_file(
         writeln!(file, "]")?;
     }
 
-    println!("   ✅ Miden trace written to {}", filename);
+    println!("   ✅ Miden trace written to {}", path.display());
     Ok(())
 }
 
-/// Write Plonky3 trace to a log file with custom filename
+/// Write Plonky3 trace to a log file at `path`
 fn write_plonky3_trace_to_file(
     plonky3_trace: &RowMajorMatrix<Goldilocks>,
-    filename: &str,
+    path: &Path,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    println!("   📝 Writing Plonky3 trace to {}...", filename);
+    println!("   📝 Writing Plonky3 trace to {}...", path.display());
 
-    let mut file = File::create(filename)?;
+    let mut file = File::create(path)?;
     let height = plonky3_trace.height();
     let width = plonky3_trace.width();
 
@@ -76,22 +81,110 @@ fn write_plonky3_trace_to_file(
         writeln!(file, "]")?;
     }
 
-    println!("   ✅ Plonky3 trace written to {}", filename);
+    println!("   ✅ Plonky3 trace written to {}", path.display());
     Ok(())
 }
 
+/// Write `trace` to `path` as little-endian `u64`s: height, width, then the canonical
+/// values column by column
+///
+/// Much smaller and faster than the text logs for large traces; read it back with
+/// [`read_trace_binary`].
+pub fn write_trace_binary(
+    path: impl AsRef<Path>,
+    trace: &RowMajorMatrix<Goldilocks>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    let (height, width) = (trace.height(), trace.width());
+
+    writer.write_all(&(height as u64).to_le_bytes())?;
+    writer.write_all(&(width as u64).to_le_bytes())?;
+    for col in 0..width {
+        for row in 0..height {
+            let value = trace.values[row * width + col].as_canonical_u64();
+            writer.write_all(&value.to_le_bytes())?;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Read a trace written by [`write_trace_binary`]
+pub fn read_trace_binary(
+    path: impl AsRef<Path>,
+) -> Result<RowMajorMatrix<Goldilocks>, Box<dyn std::error::Error>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut read_u64 = || -> std::io::Result<u64> {
+        let mut bytes = [0u8; 8];
+        reader.read_exact(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
+    };
+
+    let height = usize::try_from(read_u64()?)?;
+    let width = usize::try_from(read_u64()?)?;
+    let len = height
+        .checked_mul(width)
+        .ok_or_else(|| format!("trace dimensions {height}×{width} overflow"))?;
+
+    let mut values = vec![Goldilocks::ZERO; len];
+    for col in 0..width {
+        for row in 0..height {
+            values[row * width + col] = Goldilocks::from_u64(read_u64()?);
+        }
+    }
+    if read_u64().is_ok() {
+        return Err("trailing data after trace values".into());
+    }
+    Ok(RowMajorMatrix::new(values, width))
+}
+
 /// IncrementAir defines the arithmetic constraints for our increment proof
-/// This AIR enforces that the first column of each row increments by 1 from the previous row
-/// i.e., trace[i][0] = trace[i-1][0] + 1 for all transition rows
+/// This AIR enforces that the first column of each row increments by `step` from the previous row
+/// i.e., trace[i][0] = trace[i-1][0] + step for all transition rows
+///
+/// Padding rows are marked by the converter's padding selector, which it appends as
+/// the last column, rather than by a public row count. The selector must be 0 or 1,
+/// may only switch from 0 to 1, and every other cell of a padding row must be zero,
+/// so a prover cannot hide data in the padding. The increment is not enforced on the
+/// transition into a padding row.
 #[derive(Clone)]
-pub struct IncrementAir;
+pub struct IncrementAir {
+    /// Amount column 0 grows by on each transition; Miden's clock always uses 1
+    pub step: Val,
+    /// Number of trace columns, the last of which is the padding selector
+    pub width: usize,
+}
+
+impl Default for IncrementAir {
+    fn default() -> Self {
+        Self {
+            step: Val::ONE,
+            width: NUM_COLS,
+        }
+    }
+}
+
+impl IncrementAir {
+    /// The default AIR sized to `trace`, so it follows Miden's trace width
+    pub fn for_trace<F: Clone + Send + Sync>(trace: &RowMajorMatrix<F>) -> Self {
+        Self {
+            width: trace.width(),
+            ..Self::default()
+        }
+    }
+
+    fn padding_col(&self) -> usize {
+        self.width - 1
+    }
+}
 
 /// BaseAir implementation tells Plonky3 the basic properties of our computation
 impl<F> BaseAir<F> for IncrementAir {
     /// Returns the number of columns in our execution trace
-    /// Our trace has 80 columns as determined from Miden VM
+    /// A converted Miden trace has Miden VM's columns plus the range limbs and the padding
+    /// selector
     fn width(&self) -> usize {
-        NUM_COLS
+        self.width
     }
 }
 
@@ -113,43 +206,201 @@ impl<AB: AirBuilder> Air<AB> for IncrementAir {
                 .expect("Matrix must have at least two rows for transitions"),
         );
 
+        let padding_col = self.padding_col();
+        let is_padding = current_row[padding_col];
+        let next_is_padding = next_row[padding_col];
+
+        // Padding rows hold zero in every column but the selector
+        builder.assert_bool(is_padding);
+        for &cell in &current_row[..padding_col] {
+            builder.assert_zero(is_padding * cell);
+        }
+
         // Apply constraint only during transitions (between consecutive rows)
         // This excludes boundary conditions (first/last rows)
         let mut when_transition = builder.when_transition();
 
-        // The core constraint: next_row[0] - current_row[0] = 1
-        // This ensures that the first column increments by exactly 1 each row
-        // AB::Expr::from(AB::F::ONE) creates the field element representing 1
-        when_transition.assert_eq(
-            next_row[0].clone() - current_row[0].clone(),
-            AB::Expr::from(AB::F::ONE),
-        );
+        // Padding is a suffix: once a row is padding, so is the next one
+        when_transition.assert_zero(is_padding * (AB::Expr::ONE - next_is_padding));
+
+        // The core constraint: next_row[0] - current_row[0] = step, unless the next
+        // row is padding. This ensures that the first column increments by exactly
+        // `step` each real row.
+        // The builder is generic over its field, so `step` is lifted via its canonical value
+        let step = AB::F::from_u64(self.step.as_canonical_u64());
+        when_transition
+            .assert_zero((AB::Expr::ONE - next_is_padding) * (next_row[0] - current_row[0] - step));
     }
 }
 
+impl IncrementAir {
+    /// Check the increment constraint directly on a trace, before any proving setup
+    ///
+    /// Returns the first row `i` where `trace[i][0] != trace[i-1][0] + step`, or where
+    /// the padding constraints fail, together with a description of the mismatch.
+    pub fn check<F: PrimeField64>(&self, trace: &RowMajorMatrix<F>) -> Result<(), (usize, String)> {
+        let step = F::from_u64(self.step.as_canonical_u64());
+        let padding_col = self.padding_col();
+        let is_padding = |row: &[F]| row.get(padding_col).is_some_and(|s| s.is_one());
+
+        for (row, cells) in trace.values.chunks(trace.width()).enumerate() {
+            if let Some(&selector) = cells.get(padding_col) {
+                if !selector.is_zero() && !selector.is_one() {
+                    return Err((row, format!("padding selector is {selector}, not 0 or 1")));
+                }
+                if selector.is_one() {
+                    if let Some(col) = cells[..padding_col].iter().position(|x| !x.is_zero()) {
+                        return Err((
+                            row,
+                            format!("padding row holds {} in column {col}", cells[col]),
+                        ));
+                    }
+                }
+            }
+
+            if row == 0 {
+                continue;
+            }
+            let prev_cells = trace.row_slice(row - 1).expect("row is in range");
+            if is_padding(&prev_cells) && !is_padding(cells) {
+                return Err((row, format!("real row {row} follows a padding row")));
+            }
+            if is_padding(cells) {
+                continue;
+            }
+
+            let (prev, curr) = (prev_cells[0], cells[0]);
+            if curr != prev + step {
+                return Err((
+                    row,
+                    format!(
+                        "column 0 goes from {} at row {} to {} at row {}, expected an increment of {}",
+                        prev.as_canonical_u64(),
+                        row - 1,
+                        curr.as_canonical_u64(),
+                        row,
+                        self.step.as_canonical_u64()
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Check that a Plonky3 trace holds the same execution as a Miden trace
+///
+/// Converts `miden_trace` and compares its execution rows with `p3_matrix`; padding
+/// rows and the rows Miden fills with random values are not compared. Returns `false`
+/// if the widths differ, `p3_matrix` is too short, or any execution row differs.
+pub fn attest_same_trace(
+    miden_trace: &miden_processor::ExecutionTrace,
+    p3_matrix: &RowMajorMatrix<Goldilocks>,
+) -> bool {
+    let Ok(converted) = TraceConverter::convert::<Goldilocks>(miden_trace) else {
+        return false;
+    };
+    let original_height = miden_trace.length();
+
+    if p3_matrix.width() != converted.width() || p3_matrix.height() < original_height {
+        return false;
+    }
+
+    let real_len = (original_height - ExecutionTrace::NUM_RAND_ROWS) * converted.width();
+    p3_matrix.values[..real_len] == converted.values[..real_len]
+}
+
+/// Check cell by cell that a converted trace holds the Miden trace's values
+///
+/// Every real row and Miden column is compared, except column 0 on the last row, which
+/// [`trace_gen`] overwrites with a [`LastRowFixup`]. Padding rows and the columns the
+/// converter appends have no Miden counterpart and are skipped. Returns
+/// the first mismatch with its coordinates.
+pub(crate) fn assert_traces_equivalent(
+    miden: &ExecutionTrace,
+    p3: &RowMajorMatrix<Goldilocks>,
+) -> Result<(), String> {
+    let height = miden.length();
+    let width = miden.main_trace_width();
+    if p3.height() < height || p3.width() < width {
+        return Err(format!(
+            "Plonky3 trace is {}×{}, smaller than the {}×{} Miden trace",
+            p3.height(),
+            p3.width(),
+            height,
+            width
+        ));
+    }
+
+    let main_segment = miden.main_segment();
+    for c in 0..width {
+        let column = main_segment.get_column(c);
+        let rows = if c == 0 { height - 1 } else { height };
+        for (r, value) in column.iter().enumerate().take(rows) {
+            let p3_value = p3.values[r * p3.width() + c].as_canonical_u64();
+            if p3_value != value.as_int() {
+                return Err(format!(
+                    "row {}, column {}: Plonky3 holds {} but Miden holds {}",
+                    r,
+                    c,
+                    p3_value,
+                    value.as_int()
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Assemble and execute a Miden program with empty inputs
+///
+/// Assembly and execution failures are returned rather than aborting, so a bad
+/// program surfaces as an `Err` from [`trace_gen`] and the examples.
+pub fn execute_masm(
+    masm_code: &str,
+) -> Result<(miden_processor::ExecutionTrace, miden_vm::Program), Box<dyn std::error::Error>> {
+    println!("   📝 Assembling Miden program...");
+    let program = Assembler::default()
+        .assemble_program(masm_code)
+        .map_err(|e| format!("Failed to compile Miden Assembly code: {e}"))?;
+
+    println!("   ▶️  Executing Miden program...");
+    let stack_inputs = StackInputs::default();
+    let advice_inputs = AdviceInputs::default();
+    let mut host = DefaultHost::default();
+    let options = ExecutionOptions::default();
+
+    let miden_trace = execute(&program, stack_inputs, advice_inputs, &mut host, options)?;
+
+    Ok((miden_trace, program))
+}
+
+/// Traces and execution parameters produced by [`trace_gen`]
+pub type TraceGenOutput = (
+    miden_processor::ExecutionTrace,
+    RowMajorMatrix<Goldilocks>,
+    miden_vm::Program,
+    miden_vm::StackInputs,
+    miden_vm::AdviceInputs,
+);
+
 /// Generate traces for a given number of Fibonacci iterations
 ///
 /// Returns both the Miden VM execution trace and the converted Plonky3 trace.
 /// Also returns the program and inputs needed for proof generation.
-/// Writes traces to files: fib_{fib_iter}_trace_miden.log and fib_{fib_iter}_trace_p3.log
+/// Writes traces to fib_{fib_iter}_trace_miden.log and fib_{fib_iter}_trace_p3.log in `log_dir`
 ///
 /// # Arguments
 /// * `fib_iter` - Number of Fibonacci iterations to compute
+/// * `log_dir` - Existing directory the trace logs are written to
 ///
 /// # Returns
 /// * `(ExecutionTrace, RowMajorMatrix<Goldilocks>, Program, StackInputs, AdviceInputs)` - Tuple of traces and execution parameters
 pub fn trace_gen(
     fib_iter: usize,
-) -> Result<
-    (
-        miden_processor::ExecutionTrace,
-        RowMajorMatrix<Goldilocks>,
-        miden_vm::Program,
-        miden_vm::StackInputs,
-        miden_vm::AdviceInputs,
-    ),
-    Box<dyn std::error::Error>,
-> {
+    log_dir: impl AsRef<Path>,
+) -> Result<TraceGenOutput, Box<dyn std::error::Error>> {
     println!("🚀 Generating trace using Miden VM execution...");
     // Create a simple Fibonacci program in Miden Assembly
     // This creates a computation with incrementing steps suitable for our constraint
@@ -175,19 +426,7 @@ pub fn trace_gen(
         fib_iter, fib_iter
     );
 
-    println!("   📝 Assembling Miden program...");
-    let program = Assembler::default()
-        .assemble_program(masm_code)
-        .expect("Failed to compile Miden Assembly code");
-
-    println!("   ▶️  Executing Miden program...");
-    let stack_inputs = StackInputs::default();
-    let advice_inputs = AdviceInputs::default();
-    let mut host = DefaultHost::default();
-    let options = ExecutionOptions::default();
-
-    let miden_trace = execute(&program, stack_inputs, advice_inputs, &mut host, options)
-        .expect("Failed to execute Miden program");
+    let (miden_trace, program) = execute_masm(&masm_code)?;
 
     println!("   ✅ Miden execution completed");
     println!(
@@ -196,15 +435,17 @@ pub fn trace_gen(
         miden_trace.main_trace_width()
     );
 
-    // Write the Miden trace to log file with custom filename
-    let miden_filename = format!("fib_{}_trace_miden.log", fib_iter);
-    write_miden_trace_to_file(&miden_trace, &miden_filename)?;
-
     // Convert the Miden trace to Plonky3 format
     println!("   🔄 Converting trace to Plonky3 format...");
     let conversion_start = std::time::Instant::now();
 
-    let plonky3_trace = convert_miden_trace::<Goldilocks>(&miden_trace)?;
+    // Miden's random last row would break the clock increment IncrementAir checks
+    let options = ConversionOptions {
+        last_row_fixup: Some(LastRowFixup::continue_clock(&miden_trace)),
+        ..Default::default()
+    };
+    let (plonky3_trace, ..) =
+        convert_miden_execution_with_options::<Goldilocks>(&miden_trace, &options)?;
 
     let conversion_time = conversion_start.elapsed();
     println!(
@@ -227,18 +468,27 @@ pub fn trace_gen(
     );
     println!("      Padding rows added: {}", stats.padding_rows);
 
-    // Verify the trace width matches our expectations
-    assert_eq!(
-        plonky3_trace.width(),
-        NUM_COLS,
-        "Trace width {} should match NUM_COLS {}",
-        plonky3_trace.width(),
-        NUM_COLS
+    // Fail fast if the clock column does not match the increment constraint
+    IncrementAir::for_trace(&plonky3_trace)
+        .check(&plonky3_trace)
+        .map_err(|(row, msg)| {
+            format!("Converted trace violates IncrementAir at row {row}: {msg}")
+        })?;
+
+    // The two log files written below must agree on every real row
+    debug_assert_eq!(
+        assert_traces_equivalent(&miden_trace, &plonky3_trace),
+        Ok(())
     );
 
+    // Write the Miden trace to log file with custom filename
+    let log_dir = log_dir.as_ref();
+    let miden_path = log_dir.join(format!("fib_{}_trace_miden.log", fib_iter));
+    write_miden_trace_to_file(&miden_trace, &miden_path)?;
+
     // Write the Plonky3 trace to log file with custom filename
-    let p3_filename = format!("fib_{}_trace_p3.log", fib_iter);
-    write_plonky3_trace_to_file(&plonky3_trace, &p3_filename)?;
+    let p3_path = log_dir.join(format!("fib_{}_trace_p3.log", fib_iter));
+    write_plonky3_trace_to_file(&plonky3_trace, &p3_path)?;
 
     // Convert inputs to miden_vm types for proof generation
     let vm_stack_inputs = VmStackInputs::default();
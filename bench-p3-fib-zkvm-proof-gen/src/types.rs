@@ -1,22 +1,27 @@
 use p3_blake3::Blake3;
-use p3_challenger::{HashChallenger, SerializingChallenger64};
+use p3_challenger::{DuplexChallenger, HashChallenger, SerializingChallenger64};
 use p3_commit::ExtensionMmcs;
 use p3_dft::Radix2DitParallel;
 use p3_field::extension::BinomialExtensionField;
 use p3_fri::TwoAdicFriPcs;
-use p3_goldilocks::Goldilocks;
+use p3_goldilocks::{Goldilocks, Poseidon2Goldilocks};
 use p3_keccak::{Keccak256Hash, KeccakF};
 use p3_merkle_tree::MerkleTreeMmcs;
-use p3_symmetric::{CompressionFunctionFromHasher, PaddingFreeSponge, SerializingHasher};
+use p3_symmetric::{
+    CompressionFunctionFromHasher, PaddingFreeSponge, SerializingHasher, TruncatedPermutation,
+};
+use p3_trace_convertor::layout::IS_PADDING_COL;
 use p3_uni_stark::StarkConfig;
 
-// Number of columns in our trace matrix (will be updated dynamically based on Miden trace)
-pub const NUM_COLS: usize = 80; // Updated to match Miden VM trace width
+// Default number of columns in our trace matrix; `IncrementAir::for_trace` follows the
+// actual width of the converted Miden trace instead
+pub const NUM_COLS: usize = IS_PADDING_COL + 1; // Miden's columns, the range limbs and the selector
 
 // Number of columns for synthetic Plonky3 traces
 pub const SYNTHETIC_TRACE_COLS: usize = 4;
 
-// Number of Fibonacci steps to compute in the Miden program
+// Default number of Fibonacci steps to compute in the Miden program; `trace_gen` takes
+// the count as an argument
 pub const FIBONACCI_STEPS: usize = 70;
 
 // Type aliases for cleaner signatures
@@ -65,3 +70,19 @@ pub type Blake3ChallengeMmcs = ExtensionMmcs<Val, Challenge, Blake3ValMmcs>;
 pub type Blake3Challenger = SerializingChallenger64<Val, HashChallenger<u8, Blake3ByteHash, 32>>;
 pub type Blake3Pcs = TwoAdicFriPcs<Val, Dft, Blake3ValMmcs, Blake3ChallengeMmcs>;
 pub type Blake3Config = StarkConfig<Blake3Pcs, Challenge, Blake3Challenger>;
+
+// Poseidon2-based type definitions, an algebraic hash over Goldilocks itself
+pub type Poseidon2Perm = Poseidon2Goldilocks<16>;
+pub type Poseidon2Hash = PaddingFreeSponge<Poseidon2Perm, 16, 8, 8>;
+pub type Poseidon2Compress = TruncatedPermutation<Poseidon2Perm, 2, 8, 16>;
+pub type Poseidon2ValMmcs = MerkleTreeMmcs<
+    <Val as p3_field::Field>::Packing,
+    <Val as p3_field::Field>::Packing,
+    Poseidon2Hash,
+    Poseidon2Compress,
+    8,
+>;
+pub type Poseidon2ChallengeMmcs = ExtensionMmcs<Val, Challenge, Poseidon2ValMmcs>;
+pub type Poseidon2Challenger = DuplexChallenger<Val, Poseidon2Perm, 16, 8>;
+pub type Poseidon2Pcs = TwoAdicFriPcs<Val, Dft, Poseidon2ValMmcs, Poseidon2ChallengeMmcs>;
+pub type Poseidon2Config = StarkConfig<Poseidon2Pcs, Challenge, Poseidon2Challenger>;
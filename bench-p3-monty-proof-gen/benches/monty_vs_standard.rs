@@ -0,0 +1,67 @@
+//! End-to-end proving time in Montgomery-form against standard-form Goldilocks
+//!
+//! `poseidon_comparison.rs` in `goldilocks-monty` only times the permutation. This
+//! proves the same `FibLikeAir` trace with `p3_goldilocks_monty::Goldilocks` (this
+//! crate) and `p3_goldilocks::Goldilocks` (the `p3` crate), so the difference covers
+//! the DFT, constraint evaluation and FRI as well.
+//!
+//! Both sides commit with Blake3 over 8-byte serialized values, so hashing costs the
+//! same and only the field arithmetic differs.
+//!
+//! ## Running Benchmarks
+//!
+//! ```bash
+//! cargo bench -p p3-monty --bench monty_vs_standard
+//! ```
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use p3_field::PrimeField64;
+use p3_uni_stark::prove;
+
+const LOG_NUM_STEPS: usize = 14;
+const NUM_COL: usize = 80;
+
+fn bench_prove_monty_vs_standard(c: &mut Criterion) {
+    // Both generators draw from the same fixed seed, so the traces hold the same values
+    let (monty_trace, monty_result) = p3_monty::generate_trace(1 << LOG_NUM_STEPS, NUM_COL);
+    let (standard_trace, standard_result) =
+        p3::generate_trace::<p3_uni_stark::Val<p3::Blake3Config>>(1 << LOG_NUM_STEPS, NUM_COL);
+    assert!(monty_trace
+        .values
+        .iter()
+        .zip(&standard_trace.values)
+        .all(|(m, s)| m.as_canonical_u64() == s.as_canonical_u64()));
+
+    let monty_air = p3_monty::FibLikeAir {
+        num_col: NUM_COL,
+        exponent: p3_monty::DEFAULT_EXPONENT,
+    };
+    let standard_air = p3::FibLikeAir {
+        num_col: NUM_COL,
+        exponent: p3::DEFAULT_EXPONENT,
+    };
+
+    let mut group = c.benchmark_group(format!("prove_blake3_2^{LOG_NUM_STEPS}x{NUM_COL}"));
+    group.sample_size(10);
+
+    group.bench_function("goldilocks_monty", |b| {
+        b.iter_batched(
+            || (p3_monty::blake3_config(), monty_trace.clone()),
+            |(config, trace)| prove(&config, &monty_air, trace, &vec![monty_result]),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function("goldilocks", |b| {
+        b.iter_batched(
+            || (p3::blake3_config(), standard_trace.clone()),
+            |(config, trace)| prove(&config, &standard_air, trace, &vec![standard_result]),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_prove_monty_vs_standard);
+criterion_main!(benches);
@@ -1,10 +1,8 @@
-use p3_air::{Air, AirBuilder, BaseAir};
 use p3_blake3::Blake3;
 use p3_challenger::{DuplexChallenger, HashChallenger, SerializingChallenger64};
 use p3_commit::ExtensionMmcs;
 use p3_dft::Radix2DitParallel;
 use p3_field::extension::BinomialExtensionField;
-use p3_field::PrimeCharacteristicRing;
 use p3_fri::{FriParameters, TwoAdicFriPcs};
 use p3_goldilocks_monty::{Goldilocks, Poseidon2Goldilocks};
 use p3_keccak::{Keccak256Hash, KeccakF};
@@ -13,8 +11,10 @@ use p3_merkle_tree::MerkleTreeMmcs;
 use p3_symmetric::{
     CompressionFunctionFromHasher, PaddingFreeSponge, SerializingHasher, TruncatedPermutation,
 };
-use p3_uni_stark::{prove, verify, StarkConfig};
-use rand::{rngs::SmallRng, RngCore, SeedableRng};
+use p3_uni_stark::{prove, verify, Proof, StarkConfig, StarkGenericConfig};
+use rand::{rngs::SmallRng, SeedableRng};
+use serde::Serialize;
+use std::time::{Duration, Instant};
 use tracing::{debug, info, info_span, instrument};
 
 // TRACE_WIDTH is now dynamic based on num_col
@@ -66,111 +66,46 @@ pub type Blake3Challenger = SerializingChallenger64<Val, HashChallenger<u8, Blak
 pub type Blake3Pcs = TwoAdicFriPcs<Val, Radix2DitParallel<Val>, Blake3ValMmcs, Blake3ChallengeMmcs>;
 pub type Blake3Config = StarkConfig<Blake3Pcs, Challenge, Blake3Challenger>;
 
-#[derive(Clone)]
-pub struct FibLikeAir {
-    pub final_result: Val,
-    pub num_col: usize,
-}
+pub use p3_fib_air::{FibLikeAir, DEFAULT_EXPONENT, DEFAULT_SEED};
 
-impl<F> BaseAir<F> for FibLikeAir {
-    fn width(&self) -> usize {
-        self.num_col
-    }
+/// Trace satisfying a [`FibLikeAir`], starting from the [`DEFAULT_SEED`] random row
+pub fn generate_trace(num_steps: usize, num_col: usize) -> (RowMajorMatrix<Val>, Val) {
+    generate_trace_seeded(num_steps, num_col, DEFAULT_SEED)
 }
 
-impl<AB: AirBuilder> Air<AB> for FibLikeAir {
-    fn eval(&self, builder: &mut AB) {
-        let main = builder.main();
-        let local = main.row_slice(0).expect("Matrix is empty?");
-        let next = main.row_slice(1).expect("Matrix only has 1 row?");
-
-        // Get all local variables
-        let x1 = local[0].clone();
-
-        // Constraint: x_1^8 + x_2 + ... + x_{num_col-1} = x_num_col
-        let x1_pow8 = x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone();
-
-        let mut sum = x1_pow8;
-
-        // Add x_2 through x_{num_col-1}
-        for i in 1..self.num_col - 1 {
-            sum = sum + local[i].clone();
-        }
-
-        // Assert sum equals x_num_col (last column)
-        builder.assert_zero(sum - local[self.num_col - 1].clone());
-
-        // Transition constraint: next_x1 = current x_num_col
-        let next_x1 = next[0].clone();
-        builder
-            .when_transition()
-            .assert_eq(next_x1, local[self.num_col - 1].clone());
+/// Trace satisfying a [`FibLikeAir`], starting from a random row drawn from `seed`
+///
+/// The row is drawn with `SmallRng` as in `p3`, so the same seed gives both
+/// crates the same canonical trace and their benchmarks prove identical inputs.
+pub fn generate_trace_seeded(
+    num_steps: usize,
+    num_col: usize,
+    seed: u64,
+) -> (RowMajorMatrix<Val>, Val) {
+    logged_generate_trace(num_steps, num_col, seed, DEFAULT_EXPONENT)
+}
 
-        // No initial constraints needed - allowing random starting values
-    }
+/// Trace satisfying a [`FibLikeAir`] whose main constraint raises `x_1` to `exponent`
+pub fn generate_trace_with_exponent(
+    num_steps: usize,
+    num_col: usize,
+    exponent: u32,
+) -> (RowMajorMatrix<Val>, Val) {
+    logged_generate_trace(num_steps, num_col, DEFAULT_SEED, exponent)
 }
 
-pub fn generate_trace(num_steps: usize, num_col: usize) -> (RowMajorMatrix<Val>, Val) {
+fn logged_generate_trace(
+    num_steps: usize,
+    num_col: usize,
+    seed: u64,
+    exponent: u32,
+) -> (RowMajorMatrix<Val>, Val) {
     debug!(
         "Starting trace generation: {} steps, {} columns",
         num_steps, num_col
     );
-    let mut rng = SmallRng::seed_from_u64(123);
-    assert!(num_steps.is_power_of_two());
-    assert!(num_col >= 2, "num_col must be at least 2");
-
-    let mut values = Vec::with_capacity(num_steps * num_col);
-
-    // Initialize first row: need to satisfy x_1^8 + x_2 + ... + x_{num_col-1} = x_num_col
-    let mut current_row = (0..num_col)
-        .map(|_| Val::from_u32(rng.next_u32()))
-        .collect::<Vec<_>>();
-
-    // Make the first row satisfy the constraint: x_1^8 + x_2 + ... + x_{num_col-1} = x_num_col
-    let x1_pow8 = current_row[0].exp_u64(8); // 1^8 = 1
-    let mut sum = x1_pow8;
-    for i in 1..num_col - 1 {
-        sum += current_row[i]; // Add x_2, x_3, ..., x_{num_col-1}
-    }
-    current_row[num_col - 1] = sum; // Set x_num_col = sum
-
-    for step in 0..num_steps {
-        // Add current row to trace
-        values.extend_from_slice(&current_row);
-
-        // Compute next row if not the last step
-        if step < num_steps - 1 {
-            let mut next_row = vec![Val::ZERO; num_col];
-
-            // x_1 of next row = x_num_col of current row
-            next_row[0] = current_row[num_col - 1];
-
-            // For columns 1 to num_col-2: set to 1 for simplicity
-            for i in 1..num_col - 1 {
-                next_row[i] = Val::ONE;
-            }
-
-            // x_num_col = x_1^8 + x_2 + ... + x_{num_col-1}
-            let x1_pow8 = next_row[0].exp_u64(8);
-            let mut sum = x1_pow8;
-            for i in 1..num_col - 1 {
-                sum += next_row[i];
-            }
-            next_row[num_col - 1] = sum;
-
-            current_row = next_row;
-        }
-    }
-
-    let final_result = values[values.len() - num_col]; // First element of last row
-    let trace = RowMajorMatrix::new(values, num_col);
+    let (trace, final_result) =
+        p3_fib_air::generate_trace_with_exponent(num_steps, num_col, seed, exponent);
     info!(
         "Trace generated with {} rows, {} cols",
         trace.height(),
@@ -181,21 +116,8 @@ pub fn generate_trace(num_steps: usize, num_col: usize) -> (RowMajorMatrix<Val>,
     (trace, final_result)
 }
 
-#[instrument(level = "info", fields(num_steps, num_col, hash_type = "keccak"))]
-pub fn run_example_keccak(
-    num_steps: usize,
-    num_col: usize,
-) -> Result<(), Box<dyn std::error::Error>> {
-    info!(
-        "Generating proof for sum constraint (x1^8 + x2 + ... + x{} = x{}) with {} steps using Keccak (GoldilocksMonty simulation)",
-        num_col - 1,
-        num_col,
-        num_steps
-    );
-
-    let (trace, final_result) = generate_trace(num_steps, num_col);
-    println!("Trace size: {}x{}", trace.height(), trace.width());
-
+/// Keccak-based STARK configuration shared by the runners
+pub fn keccak_config() -> KeccakConfig {
     // Set up Keccak-based cryptography
     let byte_hash = KeccakByteHash {};
     let u64_hash = KeccakU64Hash::new(KeccakF {});
@@ -217,45 +139,11 @@ pub fn run_example_keccak(
     let pcs = KeccakPcs::new(dft, val_mmcs, fri_params);
     let challenger = KeccakChallenger::from_hasher(vec![], byte_hash);
 
-    let config = KeccakConfig::new(pcs, challenger);
-    let air = FibLikeAir {
-        final_result,
-        num_col,
-    };
-
-    info!("Starting proof generation");
-    let proof = info_span!("prove", num_steps = num_steps)
-        .in_scope(|| prove(&config, &air, trace, &vec![]));
-    info!("Proof generated successfully!");
-
-    info!("Starting proof verification");
-    match verify(&config, &air, &proof, &vec![]) {
-        Ok(()) => {
-            info!("Proof verified successfully!");
-            Ok(())
-        }
-        Err(e) => {
-            info!("Proof verification failed: {:?}", e);
-            Err(format!("Verification failed: {:?}", e).into())
-        }
-    }
+    KeccakConfig::new(pcs, challenger)
 }
 
-#[instrument(level = "info", fields(num_steps, num_col, hash_type = "poseidon2"))]
-pub fn run_example_poseidon2(
-    num_steps: usize,
-    num_col: usize,
-) -> Result<(), Box<dyn std::error::Error>> {
-    info!(
-        "Generating proof for sum constraint (x1^8 + x2 + ... + x{} = x{}) with {} steps using Poseidon2 (GoldilocksMonty simulation)",
-        num_col - 1,
-        num_col,
-        num_steps
-    );
-
-    let (trace, final_result) = generate_trace(num_steps, num_col);
-    println!("Trace size: {}x{}", trace.height(), trace.width());
-
+/// Poseidon2-based STARK configuration shared by the runners
+pub fn poseidon2_config() -> Poseidon2Config {
     // Set up Poseidon2-based cryptography
     let mut rng = SmallRng::seed_from_u64(42);
     let perm = Poseidon2Perm::new_from_rng_128(&mut rng);
@@ -277,45 +165,11 @@ pub fn run_example_poseidon2(
     let pcs = Poseidon2Pcs::new(dft, val_mmcs, fri_params);
     let challenger = Poseidon2Challenger::new(perm);
 
-    let config = Poseidon2Config::new(pcs, challenger);
-    let air = FibLikeAir {
-        final_result,
-        num_col,
-    };
-
-    info!("Starting proof generation");
-    let proof = info_span!("prove", num_steps = num_steps)
-        .in_scope(|| prove(&config, &air, trace, &vec![]));
-    info!("Proof generated successfully!");
-
-    info!("Starting proof verification");
-    match verify(&config, &air, &proof, &vec![]) {
-        Ok(()) => {
-            info!("Proof verified successfully!");
-            Ok(())
-        }
-        Err(e) => {
-            info!("Proof verification failed: {:?}", e);
-            Err(format!("Verification failed: {:?}", e).into())
-        }
-    }
+    Poseidon2Config::new(pcs, challenger)
 }
 
-#[instrument(level = "info", fields(num_steps, num_col, hash_type = "blake3"))]
-pub fn run_example_blake3(
-    num_steps: usize,
-    num_col: usize,
-) -> Result<(), Box<dyn std::error::Error>> {
-    info!(
-        "Generating proof for sum constraint (x1^8 + x2 + ... + x{} = x{}) with {} steps using Blake3",
-        num_col - 1,
-        num_col,
-        num_steps
-    );
-
-    let (trace, final_result) = generate_trace(num_steps, num_col);
-    println!("Trace size: {}x{}", trace.height(), trace.width());
-
+/// Blake3-based STARK configuration shared by the runners
+pub fn blake3_config() -> Blake3Config {
     // Set up Blake3-based cryptography
     let byte_hash = Blake3ByteHash {};
     let blake3_hash = Blake3 {};
@@ -337,31 +191,182 @@ pub fn run_example_blake3(
     let pcs = Blake3Pcs::new(dft, val_mmcs, fri_params);
     let challenger = Blake3Challenger::from_hasher(vec![], byte_hash);
 
-    let config = Blake3Config::new(pcs, challenger);
+    Blake3Config::new(pcs, challenger)
+}
+
+#[instrument(level = "info", fields(num_steps, num_col, hash_type = "keccak"))]
+pub fn run_example_keccak(
+    num_steps: usize,
+    num_col: usize,
+) -> Result<ProofMetrics, Box<dyn std::error::Error>> {
+    info!(
+        "Generating proof for sum constraint (x1^8 + x2 + ... + x{} = x{}) with {} steps using Keccak (GoldilocksMonty simulation)",
+        num_col - 1,
+        num_col,
+        num_steps
+    );
+
+    let (trace, final_result) = generate_trace(num_steps, num_col);
+    println!("Trace size: {}x{}", trace.height(), trace.width());
+
+    let config = keccak_config();
+    let air = FibLikeAir {
+        num_col,
+        exponent: DEFAULT_EXPONENT,
+    };
+
+    let metrics = prove_with_metrics(&config, &air, trace, final_result)?;
+    info!("Proof size: {} bytes", metrics.proof_bytes);
+    Ok(metrics)
+}
+
+#[instrument(level = "info", fields(num_steps, num_col, hash_type = "poseidon2"))]
+pub fn run_example_poseidon2(
+    num_steps: usize,
+    num_col: usize,
+) -> Result<ProofMetrics, Box<dyn std::error::Error>> {
+    info!(
+        "Generating proof for sum constraint (x1^8 + x2 + ... + x{} = x{}) with {} steps using Poseidon2 (GoldilocksMonty simulation)",
+        num_col - 1,
+        num_col,
+        num_steps
+    );
+
+    let (trace, final_result) = generate_trace(num_steps, num_col);
+    println!("Trace size: {}x{}", trace.height(), trace.width());
+
+    let config = poseidon2_config();
     let air = FibLikeAir {
-        final_result,
         num_col,
+        exponent: DEFAULT_EXPONENT,
     };
 
+    let metrics = prove_with_metrics(&config, &air, trace, final_result)?;
+    info!("Proof size: {} bytes", metrics.proof_bytes);
+    Ok(metrics)
+}
+
+#[instrument(level = "info", fields(num_steps, num_col, hash_type = "blake3"))]
+pub fn run_example_blake3(
+    num_steps: usize,
+    num_col: usize,
+) -> Result<ProofMetrics, Box<dyn std::error::Error>> {
+    info!(
+        "Generating proof for sum constraint (x1^8 + x2 + ... + x{} = x{}) with {} steps using Blake3",
+        num_col - 1,
+        num_col,
+        num_steps
+    );
+
+    let (trace, final_result) = generate_trace(num_steps, num_col);
+    println!("Trace size: {}x{}", trace.height(), trace.width());
+
+    let config = blake3_config();
+    let air = FibLikeAir {
+        num_col,
+        exponent: DEFAULT_EXPONENT,
+    };
+
+    let metrics = prove_with_metrics(&config, &air, trace, final_result)?;
+    info!("Proof size: {} bytes", metrics.proof_bytes);
+    Ok(metrics)
+}
+
+/// Prove and verify timings and proof size for one hash function
+#[derive(Clone, Debug)]
+pub struct ProofMetrics {
+    pub prove_time: Duration,
+    pub verify_time: Duration,
+    /// Length of the postcard-serialized proof
+    pub proof_bytes: usize,
+}
+
+fn prove_with_metrics<SC: StarkGenericConfig>(
+    config: &SC,
+    air: &FibLikeAir,
+    trace: RowMajorMatrix<p3_uni_stark::Val<SC>>,
+    final_result: p3_uni_stark::Val<SC>,
+) -> Result<ProofMetrics, Box<dyn std::error::Error>>
+where
+    Proof<SC>: Serialize,
+{
+    let num_steps = trace.height();
+
     info!("Starting proof generation");
+    let start = Instant::now();
     let proof = info_span!("prove", num_steps = num_steps)
-        .in_scope(|| prove(&config, &air, trace, &vec![]));
+        .in_scope(|| prove(config, air, trace, &vec![final_result]));
+    let prove_time = start.elapsed();
     info!("Proof generated successfully!");
 
-    match verify(&config, &air, &proof, &vec![]) {
-        Ok(()) => {
-            info!("Proof verified successfully!");
-            Ok(())
-        }
+    info!("Starting proof verification");
+    let start = Instant::now();
+    match verify(config, air, &proof, &vec![final_result]) {
+        Ok(()) => info!("Proof verified successfully!"),
         Err(e) => {
             info!("Proof verification failed: {:?}", e);
-            Err(format!("Verification failed: {:?}", e).into())
+            return Err(format!("Verification failed: {:?}", e).into());
         }
     }
+    let verify_time = start.elapsed();
+
+    let proof_bytes = postcard::to_allocvec(&proof)?.len();
+
+    Ok(ProofMetrics {
+        prove_time,
+        verify_time,
+        proof_bytes,
+    })
+}
+
+/// Prove the same trace with Keccak, Blake3 and Poseidon2 in turn
+///
+/// Running all three in one process gives them the same warm caches and thread
+/// pool, unlike separate `HASH_TYPE` invocations. Returns each hash name with its
+/// metrics, in that order.
+#[instrument(level = "info", fields(num_steps, num_col, hash_type = "all"))]
+pub fn run_all_hashes(
+    num_steps: usize,
+    num_col: usize,
+) -> Result<Vec<(String, ProofMetrics)>, Box<dyn std::error::Error>> {
+    let (trace, final_result) = generate_trace(num_steps, num_col);
+    let air = FibLikeAir {
+        num_col,
+        exponent: DEFAULT_EXPONENT,
+    };
+
+    let metrics = vec![
+        (
+            "keccak".to_string(),
+            prove_with_metrics(&keccak_config(), &air, trace.clone(), final_result)?,
+        ),
+        (
+            "blake3".to_string(),
+            prove_with_metrics(&blake3_config(), &air, trace.clone(), final_result)?,
+        ),
+        (
+            "poseidon2".to_string(),
+            prove_with_metrics(&poseidon2_config(), &air, trace, final_result)?,
+        ),
+    ];
+
+    for (hash, m) in &metrics {
+        info!(
+            "{}: prove {:.3}s, verify {:.3}ms, proof {} bytes",
+            hash,
+            m.prove_time.as_secs_f64(),
+            m.verify_time.as_secs_f64() * 1000.0,
+            m.proof_bytes
+        );
+    }
+
+    Ok(metrics)
 }
 
 #[cfg(test)]
 mod tests {
+    use p3_field::PrimeCharacteristicRing;
+
     use super::*;
 
     #[test]
@@ -369,6 +374,12 @@ mod tests {
         run_example_keccak(16, 3).expect("Small power8 gate test with Keccak failed");
     }
 
+    #[test]
+    fn test_keccak_reports_proof_size() {
+        let metrics = run_example_keccak(16, 3).expect("Keccak proof failed");
+        assert!(metrics.proof_bytes > 0);
+    }
+
     #[test]
     fn test_power8_gate_medium_keccak() {
         run_example_keccak(256, 4).expect("Medium power8 gate test with Keccak failed");
@@ -384,6 +395,13 @@ mod tests {
         run_example_poseidon2(256, 4).expect("Medium power8 gate test with Poseidon2 failed");
     }
 
+    #[test]
+    fn test_run_all_hashes() {
+        let metrics = run_all_hashes(16, 3).expect("Proving with every hash failed");
+        let hashes: Vec<_> = metrics.iter().map(|(hash, _)| hash.as_str()).collect();
+        assert_eq!(hashes, ["keccak", "blake3", "poseidon2"]);
+    }
+
     #[test]
     fn test_trace_generation() {
         let (trace, final_result) = generate_trace(8, 3);
@@ -416,4 +434,20 @@ mod tests {
 
         println!("Different column size tests passed");
     }
+
+    #[test]
+    fn test_seeded_trace_matches_p3() {
+        use p3_field::PrimeField64;
+
+        for seed in [0, 7, DEFAULT_SEED] {
+            let (monty, _) = generate_trace_seeded(32, 6, seed);
+            let (standard, _) =
+                p3::generate_trace_seeded::<p3_uni_stark::Val<p3::Blake3Config>>(32, 6, seed);
+            assert!(monty
+                .values
+                .iter()
+                .zip(&standard.values)
+                .all(|(m, s)| m.as_canonical_u64() == s.as_canonical_u64()));
+        }
+    }
 }
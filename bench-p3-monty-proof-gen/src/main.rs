@@ -1,6 +1,11 @@
-use p3_monty::{run_example_blake3, run_example_poseidon2};
+use p3_monty::{run_all_hashes, run_example_blake3, run_example_poseidon2, ProofMetrics};
 use std::env;
-use tracing_subscriber;
+
+/// log2 trace heights for the GoldilocksMonty runs
+const LOG_NUM_STEPS: &[usize] = &[19];
+
+/// Trace widths; `ProofMetrics` are printed for each height/width pair
+const NUM_COLS: &[usize] = &[80];
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Get number of threads from environment or use default
@@ -40,23 +45,29 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Transition: next_x1 = current_x_num_col");
     println!();
 
-    for &log_num_steps in [19].iter() {
+    for &log_num_steps in LOG_NUM_STEPS {
         let num_steps = 1 << log_num_steps;
-        for &num_col in [80].iter() {
+        for &num_col in NUM_COLS {
             println!("Number of steps: {}, Columns: {}", num_steps, num_col);
 
             match hash_type.as_str() {
                 "blake3" => {
                     println!("Running with Blake3 hash function");
-                    run_example_blake3(num_steps, num_col)?;
+                    print_metrics("blake3", &run_example_blake3(num_steps, num_col)?);
                 }
                 "poseidon2" => {
                     println!("Running with Poseidon2 hash function");
-                    run_example_poseidon2(num_steps, num_col)?;
+                    print_metrics("poseidon2", &run_example_poseidon2(num_steps, num_col)?);
+                }
+                "all" => {
+                    println!("Running with Keccak, Blake3 and Poseidon2 hash functions");
+                    for (hash, metrics) in run_all_hashes(num_steps, num_col)? {
+                        print_metrics(&hash, &metrics);
+                    }
                 }
                 _ => {
                     println!("Running with Blake3 hash function");
-                    run_example_blake3(num_steps, num_col)?;
+                    print_metrics("blake3", &run_example_blake3(num_steps, num_col)?);
                 }
             }
         }
@@ -64,3 +75,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/// One row of the hash comparison: timings and proof size
+fn print_metrics(hash: &str, metrics: &ProofMetrics) {
+    println!(
+        "  {}: prove {:.3}s, verify {:.3}ms, proof {} bytes",
+        hash,
+        metrics.prove_time.as_secs_f64(),
+        metrics.verify_time.as_secs_f64() * 1000.0,
+        metrics.proof_bytes
+    );
+}
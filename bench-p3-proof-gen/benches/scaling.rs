@@ -0,0 +1,60 @@
+//! Proving time of the Plonky3 path against the rayon thread count
+//!
+//! Proves one fixed `FibLikeAir` trace with Blake3 on dedicated rayon pools of
+//! 1, 2, 4 and 8 threads, so the reported times form the parallel scaling curve.
+//!
+//! ## Running Benchmarks
+//!
+//! Plonky3 only parallelizes with `p3-maybe-rayon/parallel`, so enable it:
+//! ```bash
+//! cargo bench -p p3 --bench scaling --features parallel
+//! ```
+//! Without the feature every thread count should take the same time.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use p3::{blake3_config, generate_trace, FibLikeAir, DEFAULT_EXPONENT};
+use p3_uni_stark::prove;
+
+const LOG_NUM_STEPS: usize = 14;
+const NUM_COL: usize = 80;
+const THREADS: &[usize] = &[1, 2, 4, 8];
+
+fn bench_prove_thread_scaling(c: &mut Criterion) {
+    let (trace, final_result) = generate_trace(1 << LOG_NUM_STEPS, NUM_COL);
+    let air = FibLikeAir {
+        num_col: NUM_COL,
+        exponent: DEFAULT_EXPONENT,
+    };
+
+    let mut group = c.benchmark_group(format!("prove_blake3_2^{LOG_NUM_STEPS}x{NUM_COL}"));
+    group.sample_size(10);
+
+    let air = &air;
+
+    for &num_threads in THREADS {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .expect("failed to build rayon pool");
+
+        group.bench_with_input(
+            BenchmarkId::new("threads", num_threads),
+            &num_threads,
+            |b, _| {
+                b.iter_batched(
+                    // The DFT caches twiddles in a `RefCell`, so each pool gets its own config
+                    || (blake3_config(), trace.clone()),
+                    |(config, trace)| {
+                        pool.install(move || prove(&config, air, trace, &vec![final_result]))
+                    },
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_prove_thread_scaling);
+criterion_main!(benches);
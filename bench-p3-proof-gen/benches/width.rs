@@ -0,0 +1,43 @@
+//! Proving time of the Plonky3 path against the trace width
+//!
+//! Proves `FibLikeAir` with Blake3 at a fixed step count and 8, 16, 40 and 80
+//! columns, so the differences isolate the LDE and commitment cost of wider traces.
+//!
+//! ## Running Benchmarks
+//!
+//! ```bash
+//! cargo bench -p p3 --bench width
+//! ```
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use p3::{blake3_config, generate_trace, FibLikeAir, DEFAULT_EXPONENT};
+use p3_uni_stark::prove;
+
+const LOG_NUM_STEPS: usize = 10;
+const WIDTHS: &[usize] = &[8, 16, 40, 80];
+
+fn bench_prove_trace_width(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("prove_blake3_2^{LOG_NUM_STEPS}"));
+    group.sample_size(10);
+
+    for &num_col in WIDTHS {
+        let (trace, final_result) = generate_trace(1 << LOG_NUM_STEPS, num_col);
+        let air = FibLikeAir {
+            num_col,
+            exponent: DEFAULT_EXPONENT,
+        };
+
+        group.bench_with_input(BenchmarkId::new("columns", num_col), &num_col, |b, _| {
+            b.iter_batched(
+                || (blake3_config(), trace.clone()),
+                |(config, trace)| prove(&config, &air, trace, &vec![final_result]),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_prove_trace_width);
+criterion_main!(benches);
@@ -0,0 +1,76 @@
+//! [`FibLikeAir`] over BabyBear, committed with Blake3
+//!
+//! Hashing matches [`run_example_blake3`](crate::run_example_blake3), so the two
+//! provers differ only in the field: 31-bit BabyBear with a degree-4 challenge
+//! extension instead of 64-bit Goldilocks with a degree-2 one.
+
+use p3_baby_bear::BabyBear;
+use p3_blake3::Blake3;
+use p3_challenger::{HashChallenger, SerializingChallenger32};
+use p3_commit::ExtensionMmcs;
+use p3_dft::Radix2DitParallel;
+use p3_field::extension::BinomialExtensionField;
+use p3_fri::TwoAdicFriPcs;
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_uni_stark::StarkConfig;
+use tracing::instrument;
+
+use crate::{run_with_config, Blake3ByteHash, Blake3Compress, Blake3FieldHash, P3ProofConfig};
+
+pub type BabyBearChallenge = BinomialExtensionField<BabyBear, 4>;
+pub type BabyBearValMmcs = MerkleTreeMmcs<BabyBear, u8, Blake3FieldHash, Blake3Compress, 32>;
+pub type BabyBearChallengeMmcs = ExtensionMmcs<BabyBear, BabyBearChallenge, BabyBearValMmcs>;
+pub type BabyBearChallenger =
+    SerializingChallenger32<BabyBear, HashChallenger<u8, Blake3ByteHash, 32>>;
+pub type BabyBearPcs =
+    TwoAdicFriPcs<BabyBear, Radix2DitParallel<BabyBear>, BabyBearValMmcs, BabyBearChallengeMmcs>;
+pub type BabyBearConfig = StarkConfig<BabyBearPcs, BabyBearChallenge, BabyBearChallenger>;
+
+/// Blake3-based STARK config over BabyBear with the given FRI settings
+pub fn babybear_config(proof_config: &P3ProofConfig) -> BabyBearConfig {
+    let byte_hash = Blake3ByteHash {};
+    let blake3_hash = Blake3 {};
+    let compress = Blake3Compress::new(blake3_hash);
+
+    let field_hash = Blake3FieldHash::new(blake3_hash);
+    let val_mmcs = BabyBearValMmcs::new(field_hash, compress);
+    let challenge_mmcs = BabyBearChallengeMmcs::new(val_mmcs.clone());
+    let dft = Radix2DitParallel::<BabyBear>::default();
+
+    let fri_params = proof_config.build(challenge_mmcs);
+
+    let pcs = BabyBearPcs::new(dft, val_mmcs, fri_params);
+    let challenger = BabyBearChallenger::from_hasher(vec![], byte_hash);
+
+    BabyBearConfig::new(pcs, challenger)
+}
+
+#[instrument(level = "info", fields(num_steps, num_col, field = "babybear"))]
+pub fn run_example_babybear(
+    num_steps: usize,
+    num_col: usize,
+    proof_config: Option<P3ProofConfig>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let proof_config = proof_config.unwrap_or_default();
+    run_with_config(
+        babybear_config(&proof_config),
+        num_steps,
+        num_col,
+        &proof_config,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power8_gate_small_babybear() {
+        run_example_babybear(16, 3, None).expect("Small power8 gate test over BabyBear failed");
+    }
+
+    #[test]
+    fn test_power8_gate_medium_babybear() {
+        run_example_babybear(256, 4, None).expect("Medium power8 gate test over BabyBear failed");
+    }
+}
@@ -0,0 +1,169 @@
+use p3_challenger::CanObserve;
+use p3_commit::{Pcs, PolynomialSpace};
+use p3_field::PrimeCharacteristicRing;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_uni_stark::{PcsError, StarkGenericConfig, Val};
+
+pub type Com<SC> = <<SC as StarkGenericConfig>::Pcs as Pcs<
+    <SC as StarkGenericConfig>::Challenge,
+    <SC as StarkGenericConfig>::Challenger,
+>>::Commitment;
+pub type PcsProverData<SC> = <<SC as StarkGenericConfig>::Pcs as Pcs<
+    <SC as StarkGenericConfig>::Challenge,
+    <SC as StarkGenericConfig>::Challenger,
+>>::ProverData;
+pub type PcsProof<SC> = <<SC as StarkGenericConfig>::Pcs as Pcs<
+    <SC as StarkGenericConfig>::Challenge,
+    <SC as StarkGenericConfig>::Challenger,
+>>::Proof;
+
+/// Public commitment to a trace, as produced by [`commit_trace`]
+///
+/// The height is kept alongside the PCS commitment since the verifier needs it to
+/// rebuild the trace domain.
+pub struct TraceCommitment<SC: StarkGenericConfig> {
+    pub commitment: Com<SC>,
+    pub degree: usize,
+}
+
+impl<SC: StarkGenericConfig> Clone for TraceCommitment<SC> {
+    fn clone(&self) -> Self {
+        Self {
+            commitment: self.commitment.clone(),
+            degree: self.degree,
+        }
+    }
+}
+
+/// Commit to a trace once so that it can be opened by several [`prove_committed`] calls
+///
+/// This is the first step of `p3_uni_stark::prove` (the trace is committed over its
+/// natural domain, so the PCS must not be zero-knowledge). The returned prover data
+/// holds the whole LDE and Merkle tree and is what makes later proofs cheap.
+pub fn commit_trace<SC: StarkGenericConfig>(
+    config: &SC,
+    trace: RowMajorMatrix<Val<SC>>,
+) -> (TraceCommitment<SC>, PcsProverData<SC>) {
+    assert!(
+        !<SC::Pcs as Pcs<SC::Challenge, SC::Challenger>>::ZK,
+        "commit_trace does not support zero-knowledge PCS"
+    );
+
+    let degree = trace.height();
+    let pcs = config.pcs();
+    let domain = pcs.natural_domain_for_degree(degree);
+    let (commitment, prover_data) = pcs.commit([(domain, trace)]);
+
+    (TraceCommitment { commitment, degree }, prover_data)
+}
+
+/// Prove that row `row` of a committed trace equals `public_values`
+///
+/// The assertion is bound into the Fiat-Shamir transcript together with the
+/// commitment, then the trace polynomials are opened at the domain point of `row`.
+/// Panics if the committed row does not match `public_values`.
+pub fn prove_committed<SC: StarkGenericConfig>(
+    config: &SC,
+    commitment: &TraceCommitment<SC>,
+    prover_data: &PcsProverData<SC>,
+    row: usize,
+    public_values: &[Val<SC>],
+) -> PcsProof<SC> {
+    let pcs = config.pcs();
+    let mut challenger = config.initialise_challenger();
+    observe_assertion(&mut challenger, commitment, row, public_values);
+
+    let point = row_point(config, commitment.degree, row);
+    let (opened_values, proof) = pcs.open(vec![(prover_data, vec![vec![point]])], &mut challenger);
+
+    assert_eq!(
+        opened_values[0][0][0],
+        embed::<SC>(public_values),
+        "committed trace row {row} does not match the public values"
+    );
+
+    proof
+}
+
+/// Verify a proof produced by [`prove_committed`]
+pub fn verify_committed<SC: StarkGenericConfig>(
+    config: &SC,
+    commitment: &TraceCommitment<SC>,
+    row: usize,
+    public_values: &[Val<SC>],
+    proof: &PcsProof<SC>,
+) -> Result<(), PcsError<SC>> {
+    let pcs = config.pcs();
+    let mut challenger = config.initialise_challenger();
+    observe_assertion(&mut challenger, commitment, row, public_values);
+
+    let domain = pcs.natural_domain_for_degree(commitment.degree);
+    let point = row_point(config, commitment.degree, row);
+    pcs.verify(
+        vec![(
+            commitment.commitment.clone(),
+            vec![(domain, vec![(point, embed::<SC>(public_values))])],
+        )],
+        proof,
+        &mut challenger,
+    )
+}
+
+fn observe_assertion<SC: StarkGenericConfig>(
+    challenger: &mut SC::Challenger,
+    commitment: &TraceCommitment<SC>,
+    row: usize,
+    public_values: &[Val<SC>],
+) {
+    challenger.observe(Val::<SC>::from_usize(commitment.degree));
+    challenger.observe(commitment.commitment.clone());
+    challenger.observe(Val::<SC>::from_usize(row));
+    challenger.observe_slice(public_values);
+}
+
+/// Domain point at which the trace polynomials take the values of `row`
+fn row_point<SC: StarkGenericConfig>(config: &SC, degree: usize, row: usize) -> SC::Challenge {
+    assert!(
+        row < degree,
+        "row {row} is outside a trace of height {degree}"
+    );
+
+    let domain = config.pcs().natural_domain_for_degree(degree);
+    (0..row).fold(SC::Challenge::from(domain.first_point()), |x, _| {
+        domain
+            .next_point(x)
+            .expect("trace domain has no next point")
+    })
+}
+
+fn embed<SC: StarkGenericConfig>(values: &[Val<SC>]) -> Vec<SC::Challenge> {
+    values.iter().copied().map(SC::Challenge::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{blake3_config, generate_trace};
+
+    #[test]
+    fn test_commit_once_prove_twice() {
+        let config = blake3_config();
+        let (trace, _) = generate_trace(64, 4);
+        let first_row = trace.row_slice(0).unwrap().to_vec();
+        let last_row = trace.row_slice(63).unwrap().to_vec();
+
+        let (commitment, prover_data) = commit_trace(&config, trace);
+
+        let first_proof = prove_committed(&config, &commitment, &prover_data, 0, &first_row);
+        let last_proof = prove_committed(&config, &commitment, &prover_data, 63, &last_row);
+
+        verify_committed(&config, &commitment, 0, &first_row, &first_proof)
+            .expect("first row assertion should verify");
+        verify_committed(&config, &commitment, 63, &last_row, &last_proof)
+            .expect("last row assertion should verify");
+
+        // A proof is bound to the assertion it was produced for
+        assert!(verify_committed(&config, &commitment, 63, &first_row, &first_proof).is_err());
+        assert!(verify_committed(&config, &commitment, 0, &last_row, &first_proof).is_err());
+    }
+}
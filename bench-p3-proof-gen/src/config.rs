@@ -0,0 +1,84 @@
+use p3_air::{Air, BaseAirWithPublicValues};
+use p3_field::Field;
+use p3_fri::FriParameters;
+use p3_uni_stark::{get_log_quotient_degree, SymbolicAirBuilder};
+
+use crate::{FRI_PROOF_OF_WORK_BITS, LOG_BLOWUP};
+
+/// FRI settings for the `run_example_*` provers
+///
+/// The default is what those provers have always used: a blowup of `2^3`, 100 queries
+/// and one grinding bit. Override single settings to sweep security levels, e.g.
+/// `P3ProofConfig::default().num_queries(50)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct P3ProofConfig {
+    pub(crate) log_blowup: usize,
+    num_queries: usize,
+    proof_of_work_bits: usize,
+    log_final_poly_len: usize,
+}
+
+impl Default for P3ProofConfig {
+    fn default() -> Self {
+        Self {
+            log_blowup: LOG_BLOWUP,
+            num_queries: 100,
+            proof_of_work_bits: FRI_PROOF_OF_WORK_BITS,
+            log_final_poly_len: 1,
+        }
+    }
+}
+
+impl P3ProofConfig {
+    pub fn log_blowup(mut self, log_blowup: usize) -> Self {
+        self.log_blowup = log_blowup;
+        self
+    }
+
+    pub fn num_queries(mut self, num_queries: usize) -> Self {
+        self.num_queries = num_queries;
+        self
+    }
+
+    pub fn proof_of_work_bits(mut self, proof_of_work_bits: usize) -> Self {
+        self.proof_of_work_bits = proof_of_work_bits;
+        self
+    }
+
+    pub fn log_final_poly_len(mut self, log_final_poly_len: usize) -> Self {
+        self.log_final_poly_len = log_final_poly_len;
+        self
+    }
+
+    /// Reject a blowup too small to prove `air`
+    ///
+    /// Plonky3 evaluates the quotient over a domain `2^log_quotient_degree` times the
+    /// trace height, which the LDE has to cover; otherwise proving panics deep inside
+    /// the PCS. [`FibLikeAir`](crate::FibLikeAir)'s degree-8 constraint needs a
+    /// `log_blowup` of at least 3.
+    pub fn check_blowup<F: Field, A: BaseAirWithPublicValues<F> + Air<SymbolicAirBuilder<F>>>(
+        &self,
+        air: &A,
+    ) -> Result<(), String> {
+        let log_quotient_degree =
+            get_log_quotient_degree::<F, A>(air, 0, air.num_public_values(), 0);
+        if self.log_blowup < log_quotient_degree {
+            return Err(format!(
+                "log_blowup {} is below the AIR's log quotient degree {}",
+                self.log_blowup, log_quotient_degree
+            ));
+        }
+        Ok(())
+    }
+
+    /// FRI parameters committing through `mmcs`
+    pub fn build<M>(&self, mmcs: M) -> FriParameters<M> {
+        FriParameters {
+            log_blowup: self.log_blowup,
+            log_final_poly_len: self.log_final_poly_len,
+            num_queries: self.num_queries,
+            proof_of_work_bits: self.proof_of_work_bits,
+            mmcs,
+        }
+    }
+}
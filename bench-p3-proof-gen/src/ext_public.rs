@@ -0,0 +1,172 @@
+use p3_air::{Air, AirBuilder, AirBuilderWithPublicValues, BaseAir};
+use p3_field::{BasedVectorSpace, PrimeCharacteristicRing};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_uni_stark::{
+    prove, verify, Proof, ProverConstraintFolder, StarkGenericConfig, SymbolicAirBuilder, Val,
+    VerificationError, VerifierConstraintFolder,
+};
+
+use crate::Challenge;
+
+/// Number of base-field coefficients per extension-field public value
+pub const EXT_DEGREE: usize = <Challenge as BasedVectorSpace<crate::Val>>::DIMENSION;
+
+/// Flatten extension-field public values into their base-field basis coefficients
+///
+/// `p3_uni_stark` only carries base-field public values, so each extension element
+/// is passed as its `DIMENSION` coefficients and reassembled by the AIR with
+/// [`ext_public_value`].
+pub fn flatten_public_values<SC: StarkGenericConfig>(values: &[SC::Challenge]) -> Vec<Val<SC>> {
+    values
+        .iter()
+        .flat_map(|value| value.as_basis_coefficients_slice().to_vec())
+        .collect()
+}
+
+/// Basis coefficients of the `index`-th extension-field public value
+///
+/// Two extension elements are equal exactly when all their coefficients are, so an
+/// extension-field equality is asserted coefficient by coefficient.
+pub fn ext_public_value<AB: AirBuilderWithPublicValues, const D: usize>(
+    builder: &AB,
+    index: usize,
+) -> [AB::PublicVar; D] {
+    let public_values = builder.public_values();
+    core::array::from_fn(|i| public_values[index * D + i])
+}
+
+/// [`prove`] with extension-field public values
+#[allow(clippy::multiple_bound_locations)]
+pub fn prove_with_ext_public_values<
+    SC,
+    #[cfg(debug_assertions)] A: for<'a> Air<p3_uni_stark::DebugConstraintBuilder<'a, Val<SC>>>,
+    #[cfg(not(debug_assertions))] A,
+>(
+    config: &SC,
+    air: &A,
+    trace: RowMajorMatrix<Val<SC>>,
+    public_values: &[SC::Challenge],
+) -> Proof<SC>
+where
+    SC: StarkGenericConfig,
+    A: Air<SymbolicAirBuilder<Val<SC>>> + for<'a> Air<ProverConstraintFolder<'a, SC>>,
+{
+    prove(
+        config,
+        air,
+        trace,
+        &flatten_public_values::<SC>(public_values),
+    )
+}
+
+/// [`verify`] with extension-field public values
+pub fn verify_with_ext_public_values<SC, A>(
+    config: &SC,
+    air: &A,
+    proof: &Proof<SC>,
+    public_values: &[SC::Challenge],
+) -> Result<(), VerificationError<p3_uni_stark::PcsError<SC>>>
+where
+    SC: StarkGenericConfig,
+    A: Air<SymbolicAirBuilder<Val<SC>>> + for<'a> Air<VerifierConstraintFolder<'a, SC>>,
+{
+    verify(
+        config,
+        air,
+        proof,
+        &flatten_public_values::<SC>(public_values),
+    )
+}
+
+/// Counter over an extension-field element, bounded by extension-field public values
+///
+/// Each row holds the coefficients of one element `x` of [`Challenge`]; the transition
+/// is `x' = x + 1`. Public values are `[start, end]` and the AIR asserts the
+/// extension-field equalities `x_0 = start` and `x_{n-1} = end`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExtCounterAir;
+
+impl<F> BaseAir<F> for ExtCounterAir {
+    fn width(&self) -> usize {
+        EXT_DEGREE
+    }
+}
+
+impl<AB: AirBuilderWithPublicValues> Air<AB> for ExtCounterAir {
+    fn eval(&self, builder: &mut AB) {
+        let start = ext_public_value::<AB, EXT_DEGREE>(builder, 0);
+        let end = ext_public_value::<AB, EXT_DEGREE>(builder, 1);
+
+        let main = builder.main();
+        let local = main.row_slice(0).expect("Matrix is empty?");
+        let next = main.row_slice(1).expect("Matrix only has 1 row?");
+        let (local, next) = (local.to_vec(), next.to_vec());
+
+        for i in 0..EXT_DEGREE {
+            builder.when_first_row().assert_eq(local[i], start[i]);
+            builder.when_last_row().assert_eq(local[i], end[i]);
+        }
+
+        // Adding one only touches the constant coefficient
+        let mut transition = builder.when_transition();
+        transition.assert_eq(next[0], local[0].into() + AB::Expr::ONE);
+        for i in 1..EXT_DEGREE {
+            transition.assert_eq(next[i], local[i]);
+        }
+    }
+}
+
+/// Trace for [`ExtCounterAir`] starting from `start`, with the final element
+pub fn generate_ext_counter_trace(
+    start: Challenge,
+    num_steps: usize,
+) -> (RowMajorMatrix<crate::Val>, Challenge) {
+    assert!(num_steps.is_power_of_two());
+
+    let mut values = Vec::with_capacity(num_steps * EXT_DEGREE);
+    let mut current = start;
+    for step in 0..num_steps {
+        values.extend_from_slice(current.as_basis_coefficients_slice());
+        if step < num_steps - 1 {
+            current += Challenge::ONE;
+        }
+    }
+
+    (RowMajorMatrix::new(values, EXT_DEGREE), current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blake3_config;
+
+    #[test]
+    fn test_prove_with_extension_field_public_values() {
+        let config = blake3_config();
+        let start = Challenge::from_basis_coefficients_slice(&[
+            crate::Val::from_u64(5),
+            crate::Val::from_u64(7),
+        ])
+        .unwrap();
+        let (trace, end) = generate_ext_counter_trace(start, 64);
+        assert_eq!(trace.height(), 64);
+        assert_eq!(end, start + Challenge::from_u64(63));
+
+        let public_values = [start, end];
+        let proof = prove_with_ext_public_values(&config, &ExtCounterAir, trace, &public_values);
+        verify_with_ext_public_values(&config, &ExtCounterAir, &proof, &public_values)
+            .expect("extension-field public values should verify");
+
+        // Changing only the non-constant coefficient of `end` must be caught
+        let wrong_end = end
+            + Challenge::from_basis_coefficients_slice(&[crate::Val::ZERO, crate::Val::ONE])
+                .unwrap();
+        assert!(verify_with_ext_public_values(
+            &config,
+            &ExtCounterAir,
+            &proof,
+            &[start, wrong_end]
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,80 @@
+//! [`FibLikeAir`] over KoalaBear, committed with Blake3
+//!
+//! Same hashing and challenge-extension degree as the BabyBear prover in
+//! [`run_example_babybear`](crate::run_example_babybear); KoalaBear's smaller S-box
+//! only matters for Poseidon2, so the two should prove at about the same speed here.
+
+use p3_blake3::Blake3;
+use p3_challenger::{HashChallenger, SerializingChallenger32};
+use p3_commit::ExtensionMmcs;
+use p3_dft::Radix2DitParallel;
+use p3_field::extension::BinomialExtensionField;
+use p3_fri::TwoAdicFriPcs;
+use p3_koala_bear::KoalaBear;
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_uni_stark::StarkConfig;
+use tracing::instrument;
+
+use crate::{run_with_config, Blake3ByteHash, Blake3Compress, Blake3FieldHash, P3ProofConfig};
+
+pub type KoalaBearChallenge = BinomialExtensionField<KoalaBear, 4>;
+pub type KoalaBearValMmcs = MerkleTreeMmcs<KoalaBear, u8, Blake3FieldHash, Blake3Compress, 32>;
+pub type KoalaBearChallengeMmcs = ExtensionMmcs<KoalaBear, KoalaBearChallenge, KoalaBearValMmcs>;
+pub type KoalaBearChallenger =
+    SerializingChallenger32<KoalaBear, HashChallenger<u8, Blake3ByteHash, 32>>;
+pub type KoalaBearPcs = TwoAdicFriPcs<
+    KoalaBear,
+    Radix2DitParallel<KoalaBear>,
+    KoalaBearValMmcs,
+    KoalaBearChallengeMmcs,
+>;
+pub type KoalaBearConfig = StarkConfig<KoalaBearPcs, KoalaBearChallenge, KoalaBearChallenger>;
+
+/// Blake3-based STARK config over KoalaBear with the given FRI settings
+pub fn koalabear_config(proof_config: &P3ProofConfig) -> KoalaBearConfig {
+    let byte_hash = Blake3ByteHash {};
+    let blake3_hash = Blake3 {};
+    let compress = Blake3Compress::new(blake3_hash);
+
+    let field_hash = Blake3FieldHash::new(blake3_hash);
+    let val_mmcs = KoalaBearValMmcs::new(field_hash, compress);
+    let challenge_mmcs = KoalaBearChallengeMmcs::new(val_mmcs.clone());
+    let dft = Radix2DitParallel::<KoalaBear>::default();
+
+    let fri_params = proof_config.build(challenge_mmcs);
+
+    let pcs = KoalaBearPcs::new(dft, val_mmcs, fri_params);
+    let challenger = KoalaBearChallenger::from_hasher(vec![], byte_hash);
+
+    KoalaBearConfig::new(pcs, challenger)
+}
+
+#[instrument(level = "info", fields(num_steps, num_col, field = "koalabear"))]
+pub fn run_example_koalabear(
+    num_steps: usize,
+    num_col: usize,
+    proof_config: Option<P3ProofConfig>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let proof_config = proof_config.unwrap_or_default();
+    run_with_config(
+        koalabear_config(&proof_config),
+        num_steps,
+        num_col,
+        &proof_config,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power8_gate_small_koalabear() {
+        run_example_koalabear(16, 3, None).expect("Small power8 gate test over KoalaBear failed");
+    }
+
+    #[test]
+    fn test_power8_gate_medium_koalabear() {
+        run_example_koalabear(256, 4, None).expect("Medium power8 gate test over KoalaBear failed");
+    }
+}
@@ -1,11 +1,10 @@
-use p3_air::{Air, AirBuilder, BaseAir};
 use p3_blake3::Blake3;
 use p3_challenger::{DuplexChallenger, HashChallenger, SerializingChallenger64};
 use p3_commit::ExtensionMmcs;
 use p3_dft::Radix2DitParallel;
-use p3_field::extension::BinomialExtensionField;
-use p3_field::PrimeCharacteristicRing;
-use p3_fri::{FriParameters, TwoAdicFriPcs};
+use p3_field::extension::{BinomialExtensionField, HasTwoAdicBinomialExtension};
+use p3_field::{PrimeField, TwoAdicField};
+use p3_fri::TwoAdicFriPcs;
 use p3_goldilocks::{Goldilocks, Poseidon2Goldilocks};
 use p3_keccak::{Keccak256Hash, KeccakF};
 use p3_matrix::{dense::RowMajorMatrix, Matrix};
@@ -13,13 +12,35 @@ use p3_merkle_tree::MerkleTreeMmcs;
 use p3_symmetric::{
     CompressionFunctionFromHasher, PaddingFreeSponge, SerializingHasher, TruncatedPermutation,
 };
-use p3_uni_stark::{prove, verify, StarkConfig};
-use rand::{rngs::SmallRng, RngCore, SeedableRng};
-use tracing::{debug, info, info_span, instrument};
+use p3_uni_stark::StarkConfig;
+use rand::{rngs::SmallRng, SeedableRng};
+use tracing::{debug, info, instrument};
+
+mod babybear;
+mod committed;
+mod config;
+mod ext_public;
+mod koalabear;
+mod prover;
+mod rpo;
+mod util;
+pub use babybear::*;
+pub use committed::*;
+pub use config::*;
+pub use ext_public::*;
+pub use koalabear::*;
+pub use prover::*;
+pub use rpo::*;
+pub use util::*;
+
+pub use p3_fib_air::{FibLikeAir, DEFAULT_EXPONENT, DEFAULT_SEED};
 
 type Val = Goldilocks;
 type Challenge = BinomialExtensionField<Val, 2>;
 
+/// Default FRI blowup (as log2) of the `run_example_*` provers, see [`P3ProofConfig`]
+const LOG_BLOWUP: usize = 3;
+
 // Keccak-based type definitions
 pub type KeccakByteHash = Keccak256Hash;
 pub type KeccakU64Hash = PaddingFreeSponge<KeccakF, 25, 17, 4>;
@@ -64,111 +85,56 @@ pub type Blake3Challenger = SerializingChallenger64<Val, HashChallenger<u8, Blak
 pub type Blake3Pcs = TwoAdicFriPcs<Val, Radix2DitParallel<Val>, Blake3ValMmcs, Blake3ChallengeMmcs>;
 pub type Blake3Config = StarkConfig<Blake3Pcs, Challenge, Blake3Challenger>;
 
-#[derive(Clone)]
-pub struct FibLikeAir {
-    pub final_result: Val,
-    pub num_col: usize,
+// Blake3 with a degree-`D` challenge field; `Blake3ExtConfig<2>` is `Blake3Config`
+pub type Blake3ExtChallenge<const D: usize> = BinomialExtensionField<Val, D>;
+pub type Blake3ExtChallengeMmcs<const D: usize> =
+    ExtensionMmcs<Val, Blake3ExtChallenge<D>, Blake3ValMmcs>;
+pub type Blake3ExtPcs<const D: usize> =
+    TwoAdicFriPcs<Val, Radix2DitParallel<Val>, Blake3ValMmcs, Blake3ExtChallengeMmcs<D>>;
+pub type Blake3ExtConfig<const D: usize> =
+    StarkConfig<Blake3ExtPcs<D>, Blake3ExtChallenge<D>, Blake3Challenger>;
+
+/// Trace satisfying a [`FibLikeAir`], starting from the [`DEFAULT_SEED`] random row
+pub fn generate_trace<F: PrimeField + TwoAdicField>(
+    num_steps: usize,
+    num_col: usize,
+) -> (RowMajorMatrix<F>, F) {
+    generate_trace_seeded(num_steps, num_col, DEFAULT_SEED)
 }
 
-impl<F> BaseAir<F> for FibLikeAir {
-    fn width(&self) -> usize {
-        self.num_col
-    }
+/// Trace satisfying a [`FibLikeAir`], starting from a random row drawn from `seed`
+///
+/// The row is drawn with `SmallRng` as in `p3-monty`, so the same seed gives both
+/// crates the same canonical trace and their benchmarks prove identical inputs.
+pub fn generate_trace_seeded<F: PrimeField + TwoAdicField>(
+    num_steps: usize,
+    num_col: usize,
+    seed: u64,
+) -> (RowMajorMatrix<F>, F) {
+    logged_generate_trace(num_steps, num_col, seed, DEFAULT_EXPONENT)
 }
 
-impl<AB: AirBuilder> Air<AB> for FibLikeAir {
-    fn eval(&self, builder: &mut AB) {
-        let main = builder.main();
-        let local = main.row_slice(0).expect("Matrix is empty?");
-        let next = main.row_slice(1).expect("Matrix only has 1 row?");
-
-        // Get all local variables
-        let x1 = local[0].clone();
-
-        // Constraint: x_1^8 + x_2 + ... + x_{num_col-1} = x_num_col
-        let x1_pow8 = x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone();
-
-        let mut sum = x1_pow8;
-
-        // Add x_2 through x_{num_col-1}
-        for i in 1..self.num_col - 1 {
-            sum = sum + local[i].clone();
-        }
-
-        // Assert sum equals x_num_col (last column)
-        builder.assert_zero(sum - local[self.num_col - 1].clone());
-
-        // Transition constraint: next_x1 = current x_num_col
-        let next_x1 = next[0].clone();
-        builder
-            .when_transition()
-            .assert_eq(next_x1, local[self.num_col - 1].clone());
-
-        // No initial constraints needed - allowing random starting values
-    }
+/// Trace satisfying a [`FibLikeAir`] whose main constraint raises `x_1` to `exponent`
+pub fn generate_trace_with_exponent<F: PrimeField + TwoAdicField>(
+    num_steps: usize,
+    num_col: usize,
+    exponent: u32,
+) -> (RowMajorMatrix<F>, F) {
+    logged_generate_trace(num_steps, num_col, DEFAULT_SEED, exponent)
 }
 
-pub fn generate_trace(num_steps: usize, num_col: usize) -> (RowMajorMatrix<Val>, Val) {
+fn logged_generate_trace<F: PrimeField + TwoAdicField>(
+    num_steps: usize,
+    num_col: usize,
+    seed: u64,
+    exponent: u32,
+) -> (RowMajorMatrix<F>, F) {
     debug!(
         "Starting trace generation: {} steps, {} columns",
         num_steps, num_col
     );
-    let mut rng = SmallRng::seed_from_u64(123);
-    assert!(num_steps.is_power_of_two());
-    assert!(num_col >= 2, "num_col must be at least 2");
-
-    let mut values = Vec::with_capacity(num_steps * num_col);
-
-    // Initialize first row: need to satisfy x_1^8 + x_2 + ... + x_{num_col-1} = x_num_col
-    let mut current_row = (0..num_col)
-        .map(|_| Val::from_u32(rng.next_u32()))
-        .collect::<Vec<_>>();
-
-    // Make the first row satisfy the constraint: x_1^8 + x_2 + ... + x_{num_col-1} = x_num_col
-    let x1_pow8 = current_row[0].exp_u64(8); // 1^8 = 1
-    let mut sum = x1_pow8;
-    for i in 1..num_col - 1 {
-        sum += current_row[i]; // Add x_2, x_3, ..., x_{num_col-1}
-    }
-    current_row[num_col - 1] = sum; // Set x_num_col = sum
-
-    for step in 0..num_steps {
-        // Add current row to trace
-        values.extend_from_slice(&current_row);
-
-        // Compute next row if not the last step
-        if step < num_steps - 1 {
-            let mut next_row = vec![Val::ZERO; num_col];
-
-            // x_1 of next row = x_num_col of current row
-            next_row[0] = current_row[num_col - 1];
-
-            // For columns 1 to num_col-2: set to 1 for simplicity
-            for i in 1..num_col - 1 {
-                next_row[i] = Val::ONE;
-            }
-
-            // x_num_col = x_1^8 + x_2 + ... + x_{num_col-1}
-            let x1_pow8 = next_row[0].exp_u64(8);
-            let mut sum = x1_pow8;
-            for i in 1..num_col - 1 {
-                sum += next_row[i];
-            }
-            next_row[num_col - 1] = sum;
-
-            current_row = next_row;
-        }
-    }
-
-    let final_result = values[values.len() - num_col]; // First element of last row
-    let trace = RowMajorMatrix::new(values, num_col);
+    let (trace, final_result) =
+        p3_fib_air::generate_trace_with_exponent(num_steps, num_col, seed, exponent);
     info!(
         "Trace generated with {} rows, {} cols",
         trace.height(),
@@ -179,22 +145,8 @@ pub fn generate_trace(num_steps: usize, num_col: usize) -> (RowMajorMatrix<Val>,
     (trace, final_result)
 }
 
-#[instrument(level = "info", fields(num_steps, num_col, hash_type = "keccak"))]
-pub fn run_example_keccak(
-    num_steps: usize,
-    num_col: usize,
-) -> Result<(), Box<dyn std::error::Error>> {
-    info!(
-        "Generating proof for sum constraint (x1^8 + x2 + ... + x{} = x{}) with {} steps using Keccak",
-        num_col - 1,
-        num_col,
-        num_steps
-    );
-
-    let (trace, final_result) = generate_trace(num_steps, num_col);
-    info!("Trace size: {}x{}", trace.height(), trace.width());
-
-    // Set up Keccak-based cryptography
+/// Keccak-based STARK config over Goldilocks with the given FRI settings
+pub fn keccak_config(proof_config: &P3ProofConfig) -> KeccakConfig {
     let byte_hash = KeccakByteHash {};
     let u64_hash = KeccakU64Hash::new(KeccakF {});
     let compress = KeccakCompress::new(u64_hash);
@@ -204,55 +156,34 @@ pub fn run_example_keccak(
     let challenge_mmcs = KeccakChallengeMmcs::new(val_mmcs.clone());
     let dft = Radix2DitParallel::<Val>::default();
 
-    let fri_params = FriParameters {
-        log_blowup: 3,
-        log_final_poly_len: 1,
-        num_queries: 100,
-        proof_of_work_bits: 1,
-        mmcs: challenge_mmcs,
-    };
+    let fri_params = proof_config.build(challenge_mmcs);
 
     let pcs = KeccakPcs::new(dft, val_mmcs, fri_params);
     let challenger = KeccakChallenger::from_hasher(vec![], byte_hash);
 
-    let config = KeccakConfig::new(pcs, challenger);
-    let air = FibLikeAir {
-        final_result,
-        num_col,
-    };
-    info!("Starting proof generation");
-    let proof = info_span!("prove", num_steps = num_steps)
-        .in_scope(|| prove(&config, &air, trace, &vec![]));
-    info!("Proof generated successfully!");
-
-    match verify(&config, &air, &proof, &vec![]) {
-        Ok(()) => {
-            info!("Proof verified successfully!");
-            Ok(())
-        }
-        Err(e) => {
-            info!("Proof verification failed: {:?}", e);
-            Err(format!("Verification failed: {:?}", e).into())
-        }
-    }
+    KeccakConfig::new(pcs, challenger)
 }
 
-#[instrument(level = "info", fields(num_steps, num_col, hash_type = "poseidon2"))]
-pub fn run_example_poseidon2(
+#[instrument(level = "info", fields(num_steps, num_col, hash_type = "keccak"))]
+pub fn run_example_keccak(
     num_steps: usize,
     num_col: usize,
+    proof_config: Option<P3ProofConfig>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    info!(
-        "Generating proof for sum constraint (x1^8 + x2 + ... + x{} = x{}) with {} steps using Poseidon2",
-        num_col - 1,
+    let proof_config = proof_config.unwrap_or_default();
+    run_with_config(
+        keccak_config(&proof_config),
+        num_steps,
         num_col,
-        num_steps
-    );
-
-    let (trace, final_result) = generate_trace(num_steps, num_col);
-    println!("Trace size: {}x{}", trace.height(), trace.width());
+        &proof_config,
+    )
+}
 
-    // Set up Poseidon2-based cryptography
+/// Poseidon2-based STARK config over Goldilocks with the given FRI settings
+///
+/// The permutation's round constants are drawn from a `SmallRng` seeded with 42, so
+/// every call builds the same config.
+pub fn poseidon2_config(proof_config: &P3ProofConfig) -> Poseidon2Config {
     let mut rng = SmallRng::seed_from_u64(42);
     let perm = Poseidon2Perm::new_from_rng_128(&mut rng);
     let poseidon2_hash = Poseidon2Hash::new(perm.clone());
@@ -262,136 +193,224 @@ pub fn run_example_poseidon2(
     let challenge_mmcs = Poseidon2ChallengeMmcs::new(val_mmcs.clone());
     let dft = Radix2DitParallel::<Val>::default();
 
-    let fri_params = FriParameters {
-        log_blowup: 3,
-        log_final_poly_len: 1,
-        num_queries: 100,
-        proof_of_work_bits: 1,
-        mmcs: challenge_mmcs,
-    };
+    let fri_params = proof_config.build(challenge_mmcs);
 
     let pcs = Poseidon2Pcs::new(dft, val_mmcs, fri_params);
     let challenger = Poseidon2Challenger::new(perm);
 
-    let config = Poseidon2Config::new(pcs, challenger);
-    let air = FibLikeAir {
-        final_result,
-        num_col,
-    };
-
-    info!("Starting proof generation");
-    let proof = info_span!("prove", num_steps = num_steps)
-        .in_scope(|| prove(&config, &air, trace, &vec![]));
-    info!("Proof generated successfully!");
-
-    match verify(&config, &air, &proof, &vec![]) {
-        Ok(()) => {
-            info!("Proof verified successfully!");
-            Ok(())
-        }
-        Err(e) => {
-            info!("Proof verification failed: {:?}", e);
-            Err(format!("Verification failed: {:?}", e).into())
-        }
-    }
+    Poseidon2Config::new(pcs, challenger)
 }
 
-#[instrument(level = "info", fields(num_steps, num_col, hash_type = "blake3"))]
-pub fn run_example_blake3(
+#[instrument(level = "info", fields(num_steps, num_col, hash_type = "poseidon2"))]
+pub fn run_example_poseidon2(
     num_steps: usize,
     num_col: usize,
+    proof_config: Option<P3ProofConfig>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    info!(
-        "Generating proof for sum constraint (x1^8 + x2 + ... + x{} = x{}) with {} steps using Blake3",
-        num_col - 1,
+    let proof_config = proof_config.unwrap_or_default();
+    run_with_config(
+        poseidon2_config(&proof_config),
+        num_steps,
         num_col,
-        num_steps
-    );
+        &proof_config,
+    )
+}
 
-    let (trace, final_result) = generate_trace(num_steps, num_col);
-    println!("Trace size: {}x{}", trace.height(), trace.width());
+/// Blake3-based STARK config used by [`run_example_blake3`]
+pub fn blake3_config() -> Blake3Config {
+    blake3_config_with_extension::<2>()
+}
+
+/// Blake3-based STARK config drawing challenges from a degree-`D` extension of Goldilocks
+///
+/// A larger `D` raises the soundness of the FRI and constraint-combination challenges at
+/// the cost of slower extension arithmetic. Plonky3 provides Goldilocks extensions of
+/// degree 2 and 5.
+pub fn blake3_config_with_extension<const D: usize>() -> Blake3ExtConfig<D>
+where
+    Val: HasTwoAdicBinomialExtension<D>,
+{
+    blake3_config_with_proof_config(&P3ProofConfig::default())
+}
 
-    // Set up Blake3-based cryptography
+/// Blake3-based STARK config with the given FRI settings
+fn blake3_config_with_proof_config<const D: usize>(
+    proof_config: &P3ProofConfig,
+) -> Blake3ExtConfig<D>
+where
+    Val: HasTwoAdicBinomialExtension<D>,
+{
     let byte_hash = Blake3ByteHash {};
     let blake3_hash = Blake3 {};
     let compress = Blake3Compress::new(blake3_hash);
 
     let field_hash = Blake3FieldHash::new(blake3_hash);
     let val_mmcs = Blake3ValMmcs::new(field_hash, compress);
-    let challenge_mmcs = Blake3ChallengeMmcs::new(val_mmcs.clone());
+    let challenge_mmcs = Blake3ExtChallengeMmcs::new(val_mmcs.clone());
     let dft = Radix2DitParallel::<Val>::default();
 
-    let fri_params = FriParameters {
-        log_blowup: 3,
-        log_final_poly_len: 1,
-        num_queries: 100,
-        proof_of_work_bits: 1,
-        mmcs: challenge_mmcs,
-    };
+    let fri_params = proof_config.build(challenge_mmcs);
 
-    let pcs = Blake3Pcs::new(dft, val_mmcs, fri_params);
+    let pcs = Blake3ExtPcs::new(dft, val_mmcs, fri_params);
     let challenger = Blake3Challenger::from_hasher(vec![], byte_hash);
 
-    let config = Blake3Config::new(pcs, challenger);
-    let air = FibLikeAir {
-        final_result,
-        num_col,
-    };
-
-    info!("Starting proof generation");
-    let proof = info_span!("prove", num_steps = num_steps)
-        .in_scope(|| prove(&config, &air, trace, &vec![]));
-    info!("Proof generated successfully!");
+    Blake3ExtConfig::new(pcs, challenger)
+}
 
-    match verify(&config, &air, &proof, &vec![]) {
-        Ok(()) => {
-            info!("Proof verified successfully!");
-            Ok(())
-        }
-        Err(e) => {
-            info!("Proof verification failed: {:?}", e);
-            Err(format!("Verification failed: {:?}", e).into())
-        }
-    }
+#[instrument(level = "info", fields(num_steps, num_col, hash_type = "blake3"))]
+pub fn run_example_blake3(
+    num_steps: usize,
+    num_col: usize,
+    proof_config: Option<P3ProofConfig>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let proof_config = proof_config.unwrap_or_default();
+    run_with_config(
+        blake3_config_with_proof_config::<2>(&proof_config),
+        num_steps,
+        num_col,
+        &proof_config,
+    )
 }
 
 #[cfg(test)]
 mod tests {
+    use p3_field::PrimeCharacteristicRing;
+    use p3_uni_stark::{prove, verify};
+
     use super::*;
 
     #[test]
     fn test_power8_gate_small_keccak() {
-        run_example_keccak(16, 3).expect("Small power8 gate test with Keccak failed");
+        run_example_keccak(16, 3, None).expect("Small power8 gate test with Keccak failed");
     }
 
     #[test]
     fn test_power8_gate_medium_keccak() {
-        run_example_keccak(256, 4).expect("Medium power8 gate test with Keccak failed");
+        run_example_keccak(256, 4, None).expect("Medium power8 gate test with Keccak failed");
     }
 
     #[test]
     fn test_power8_gate_small_poseidon2() {
-        run_example_poseidon2(16, 3).expect("Small power8 gate test with Poseidon2 failed");
+        run_example_poseidon2(16, 3, None).expect("Small power8 gate test with Poseidon2 failed");
     }
 
     #[test]
     fn test_power8_gate_medium_poseidon2() {
-        run_example_poseidon2(256, 4).expect("Medium power8 gate test with Poseidon2 failed");
+        run_example_poseidon2(256, 4, None).expect("Medium power8 gate test with Poseidon2 failed");
     }
 
     #[test]
     fn test_power8_gate_small_blake3() {
-        run_example_blake3(16, 3).expect("Small power8 gate test with Blake3 failed");
+        run_example_blake3(16, 3, None).expect("Small power8 gate test with Blake3 failed");
     }
 
     #[test]
     fn test_power8_gate_medium_blake3() {
-        run_example_blake3(256, 4).expect("Medium power8 gate test with Blake3 failed");
+        run_example_blake3(256, 4, None).expect("Medium power8 gate test with Blake3 failed");
+    }
+
+    #[test]
+    fn test_low_security_proof_config() {
+        // FibLikeAir's degree-8 constraint cannot be proven with a blowup of 2
+        let too_small = P3ProofConfig::default().log_blowup(1).num_queries(10);
+        assert!(run_example_blake3(16, 3, Some(too_small)).is_err());
+
+        let proof_config = P3ProofConfig::default().num_queries(10);
+        run_example_blake3(16, 3, Some(proof_config)).expect("Low-security proof failed");
+    }
+
+    #[test]
+    fn test_default_proof_config_matches_previous_parameters() {
+        let params = P3ProofConfig::default().build(());
+        assert_eq!(params.log_blowup, 3);
+        assert_eq!(params.num_queries, 100);
+        assert_eq!(params.proof_of_work_bits, 1);
+        assert_eq!(params.log_final_poly_len, 1);
+    }
+
+    #[test]
+    fn test_prove_with_exponents() {
+        for exponent in [2, 4, 8] {
+            let (trace, final_result) = generate_trace_with_exponent(16, 3, exponent);
+            let air = FibLikeAir {
+                num_col: 3,
+                exponent,
+            };
+            let config = blake3_config();
+
+            let proof = p3_prove(&config, &air, trace, &[final_result]);
+            p3_verify(&config, &air, &proof, &[final_result])
+                .unwrap_or_else(|e| panic!("x_1^{exponent} proof failed: {e:?}"));
+        }
+    }
+
+    #[test]
+    fn test_prove_then_verify_separately() {
+        let (trace, final_result) = generate_trace(64, 4);
+        let air = FibLikeAir {
+            num_col: 4,
+            exponent: DEFAULT_EXPONENT,
+        };
+        let config = blake3_config();
+
+        let proof = p3_prove(&config, &air, trace, &[final_result]);
+        p3_verify(&config, &air, &proof, &[final_result]).expect("Proof should verify");
+    }
+
+    #[test]
+    fn test_tampered_final_result_fails_verification() {
+        let (trace, final_result) = generate_trace(64, 4);
+        let air = FibLikeAir {
+            num_col: 4,
+            exponent: DEFAULT_EXPONENT,
+        };
+        let config = blake3_config();
+
+        let proof = p3_prove(&config, &air, trace, &[final_result]);
+        assert!(p3_verify(&config, &air, &proof, &[final_result + Val::ONE]).is_err());
+    }
+
+    #[test]
+    fn test_prove_with_quintic_extension() {
+        let (trace, final_result) = generate_trace(64, 4);
+        let air = FibLikeAir {
+            num_col: 4,
+            exponent: DEFAULT_EXPONENT,
+        };
+
+        let config = blake3_config_with_extension::<5>();
+        let proof = prove(&config, &air, trace, &vec![final_result]);
+        verify(&config, &air, &proof, &vec![final_result])
+            .expect("Degree-5 extension proof should verify");
+    }
+
+    #[test]
+    fn test_prove_on_fixed_thread_pools() {
+        let (trace, final_result) = generate_trace(64, 4);
+        let config = blake3_config();
+        let air = &FibLikeAir {
+            num_col: 4,
+            exponent: DEFAULT_EXPONENT,
+        };
+
+        for num_threads in [1, 2] {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap();
+            let trace = trace.clone();
+            let proof = pool.install(move || {
+                let config = blake3_config();
+                prove(&config, air, trace, &vec![final_result])
+            });
+            verify(&config, air, &proof, &vec![final_result])
+                .unwrap_or_else(|e| panic!("{num_threads}-thread proof failed: {e:?}"));
+        }
     }
 
     #[test]
     fn test_trace_generation() {
-        let (trace, final_result) = generate_trace(8, 3);
+        let (trace, final_result) = generate_trace::<Val>(8, 3);
         assert_eq!(trace.height(), 8);
         assert_eq!(trace.width(), 3);
 
@@ -412,11 +431,11 @@ mod tests {
     #[test]
     fn test_different_column_sizes() {
         // Test with 2 columns
-        let (trace2, _) = generate_trace(4, 2);
+        let (trace2, _) = generate_trace::<Val>(4, 2);
         assert_eq!(trace2.width(), 2);
 
         // Test with 5 columns
-        let (trace5, _) = generate_trace(4, 5);
+        let (trace5, _) = generate_trace::<Val>(4, 5);
         assert_eq!(trace5.width(), 5);
 
         println!("Different column size tests passed");
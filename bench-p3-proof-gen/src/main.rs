@@ -1,6 +1,14 @@
-use p3::{run_example_blake3, run_example_keccak, run_example_poseidon2};
+use p3::{
+    run_example_babybear, run_example_blake3, run_example_keccak, run_example_koalabear,
+    run_example_poseidon2, run_example_rpo,
+};
 use std::env;
-use tracing_subscriber;
+
+/// log2 of each trace height; every selected HASH_TYPE is proved at each one
+const LOG_NUM_STEPS: &[usize] = &[19];
+
+/// Trace widths proved at every height
+const NUM_COLS: &[usize] = &[80];
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Get number of threads from environment or use default
@@ -40,30 +48,39 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Transition: next_x1 = current_x_num_col");
     println!();
 
-    // for &log_num_steps in [16, 19].iter() {
-
-    for &log_num_steps in [19].iter() {
+    for &log_num_steps in LOG_NUM_STEPS {
         let num_steps = 1 << log_num_steps;
-        // for &num_col in [40, 80].iter() {
-        for &num_col in [80].iter() {
+        for &num_col in NUM_COLS {
             println!("Number of steps: {}, Columns: {}", num_steps, num_col);
 
             match hash_type.as_str() {
                 "keccak" => {
                     println!("Running with Keccak hash function");
-                    run_example_keccak(num_steps, num_col)?;
+                    run_example_keccak(num_steps, num_col, None)?;
                 }
                 "poseidon2" => {
                     println!("Running with Poseidon2 hash function");
-                    run_example_poseidon2(num_steps, num_col)?;
+                    run_example_poseidon2(num_steps, num_col, None)?;
                 }
                 "blake3" => {
                     println!("Running with Blake3 hash function");
-                    run_example_blake3(num_steps, num_col)?;
+                    run_example_blake3(num_steps, num_col, None)?;
+                }
+                "rpo" => {
+                    println!("Running with RPO hash function");
+                    run_example_rpo(num_steps, num_col, None)?;
+                }
+                "babybear" => {
+                    println!("Running over BabyBear with Blake3 hash function");
+                    run_example_babybear(num_steps, num_col, None)?;
+                }
+                "koalabear" => {
+                    println!("Running over KoalaBear with Blake3 hash function");
+                    run_example_koalabear(num_steps, num_col, None)?;
                 }
                 _ => {
                     println!("Running with Keccak hash function");
-                    run_example_keccak(num_steps, num_col)?;
+                    run_example_keccak(num_steps, num_col, None)?;
                 }
             }
         }
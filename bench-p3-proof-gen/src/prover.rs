@@ -0,0 +1,101 @@
+use p3_air::Air;
+use p3_field::{PrimeField, TwoAdicField};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_uni_stark::{
+    prove, verify, PcsError, Proof, ProverConstraintFolder, StarkGenericConfig, SymbolicAirBuilder,
+    Val, VerificationError, VerifierConstraintFolder,
+};
+use tracing::{info, info_span};
+
+use crate::{expanded_size, generate_trace, FibLikeAir, P3ProofConfig, DEFAULT_EXPONENT};
+
+/// Prove `trace` against `air` with the given public values
+///
+/// The proving half of the `run_example_*` functions, so proving can be timed alone
+/// and the proof kept, e.g. to measure its serialized size.
+/// [`FibLikeAir`](crate::FibLikeAir) takes the trace's final result as its one
+/// public value.
+#[allow(clippy::multiple_bound_locations)]
+pub fn p3_prove<
+    SC,
+    #[cfg(debug_assertions)] A: for<'a> Air<p3_uni_stark::DebugConstraintBuilder<'a, Val<SC>>>,
+    #[cfg(not(debug_assertions))] A,
+>(
+    config: &SC,
+    air: &A,
+    trace: RowMajorMatrix<Val<SC>>,
+    public_values: &[Val<SC>],
+) -> Proof<SC>
+where
+    SC: StarkGenericConfig,
+    A: Air<SymbolicAirBuilder<Val<SC>>> + for<'a> Air<ProverConstraintFolder<'a, SC>>,
+{
+    prove(config, air, trace, &public_values.to_vec())
+}
+
+/// Verify a proof produced by [`p3_prove`]
+pub fn p3_verify<SC, A>(
+    config: &SC,
+    air: &A,
+    proof: &Proof<SC>,
+    public_values: &[Val<SC>],
+) -> Result<(), VerificationError<PcsError<SC>>>
+where
+    SC: StarkGenericConfig,
+    A: Air<SymbolicAirBuilder<Val<SC>>> + for<'a> Air<VerifierConstraintFolder<'a, SC>>,
+{
+    verify(config, air, proof, &public_values.to_vec())
+}
+
+/// Prove and verify a [`FibLikeAir`] trace of `num_steps` rows and `num_col` columns
+///
+/// The shared body of the `run_example_*` functions, which differ only in the config
+/// they build from `proof_config`.
+pub fn run_with_config<SC>(
+    config: SC,
+    num_steps: usize,
+    num_col: usize,
+    proof_config: &P3ProofConfig,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    SC: StarkGenericConfig,
+    Val<SC>: PrimeField + TwoAdicField,
+{
+    info!(
+        "Generating proof for sum constraint (x1^8 + x2 + ... + x{} = x{}) with {} steps",
+        num_col - 1,
+        num_col,
+        num_steps
+    );
+
+    let (trace, final_result) = generate_trace::<Val<SC>>(num_steps, num_col);
+    info!("Trace size: {}x{}", trace.height(), trace.width());
+    let lde_size = expanded_size(trace.height(), trace.width(), proof_config.log_blowup);
+    info!(
+        "LDE size: {} elements ({} MiB)",
+        lde_size,
+        (lde_size * size_of::<Val<SC>>()) >> 20
+    );
+
+    let air = FibLikeAir {
+        num_col,
+        exponent: DEFAULT_EXPONENT,
+    };
+    proof_config.check_blowup::<Val<SC>, _>(&air)?;
+
+    info!("Starting proof generation");
+    let proof = info_span!("prove", num_steps = num_steps)
+        .in_scope(|| p3_prove(&config, &air, trace, &[final_result]));
+    info!("Proof generated successfully!");
+
+    match p3_verify(&config, &air, &proof, &[final_result]) {
+        Ok(()) => {
+            info!("Proof verified successfully!");
+            Ok(())
+        }
+        Err(e) => {
+            info!("Proof verification failed: {:?}", e);
+            Err(format!("Verification failed: {:?}", e).into())
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! [`FibLikeAir`] over Goldilocks, committed with RPO (Rescue-Prime Optimized)
+//!
+//! The Plonky3 counterpart of the `wf` crate's RPO prover. Miden's [`Rpo256`] is
+//! wrapped as a byte hasher, so it slots into the same serializing Merkle tree and
+//! challenger as Blake3 and the two backends can be compared on the same hash.
+
+use miden_crypto::hash::rpo::Rpo256;
+use p3_challenger::{HashChallenger, SerializingChallenger64};
+use p3_commit::ExtensionMmcs;
+use p3_dft::Radix2DitParallel;
+use p3_fri::TwoAdicFriPcs;
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_symmetric::{CompressionFunctionFromHasher, CryptographicHasher, SerializingHasher};
+use p3_uni_stark::StarkConfig;
+use tracing::instrument;
+
+use crate::{run_with_config, Challenge, P3ProofConfig, Val};
+
+/// [`Rpo256`] as a Plonky3 byte hasher with a 32-byte digest
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RpoByteHash;
+
+impl CryptographicHasher<u8, [u8; 32]> for RpoByteHash {
+    fn hash_iter<I>(&self, input: I) -> [u8; 32]
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let bytes: Vec<u8> = input.into_iter().collect();
+        Rpo256::hash(&bytes).as_bytes()
+    }
+}
+
+pub type RpoFieldHash = SerializingHasher<RpoByteHash>;
+pub type RpoCompress = CompressionFunctionFromHasher<RpoByteHash, 2, 32>;
+pub type RpoValMmcs = MerkleTreeMmcs<Val, u8, RpoFieldHash, RpoCompress, 32>;
+pub type RpoChallengeMmcs = ExtensionMmcs<Val, Challenge, RpoValMmcs>;
+pub type RpoChallenger = SerializingChallenger64<Val, HashChallenger<u8, RpoByteHash, 32>>;
+pub type RpoPcs = TwoAdicFriPcs<Val, Radix2DitParallel<Val>, RpoValMmcs, RpoChallengeMmcs>;
+pub type RpoConfig = StarkConfig<RpoPcs, Challenge, RpoChallenger>;
+
+/// RPO-based STARK config over Goldilocks with the given FRI settings
+pub fn rpo_config(proof_config: &P3ProofConfig) -> RpoConfig {
+    let compress = RpoCompress::new(RpoByteHash);
+
+    let field_hash = RpoFieldHash::new(RpoByteHash);
+    let val_mmcs = RpoValMmcs::new(field_hash, compress);
+    let challenge_mmcs = RpoChallengeMmcs::new(val_mmcs.clone());
+    let dft = Radix2DitParallel::<Val>::default();
+
+    let fri_params = proof_config.build(challenge_mmcs);
+
+    let pcs = RpoPcs::new(dft, val_mmcs, fri_params);
+    let challenger = RpoChallenger::from_hasher(vec![], RpoByteHash);
+
+    RpoConfig::new(pcs, challenger)
+}
+
+#[instrument(level = "info", fields(num_steps, num_col, hash_type = "rpo"))]
+pub fn run_example_rpo(
+    num_steps: usize,
+    num_col: usize,
+    proof_config: Option<P3ProofConfig>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let proof_config = proof_config.unwrap_or_default();
+    run_with_config(rpo_config(&proof_config), num_steps, num_col, &proof_config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power8_gate_small_rpo() {
+        run_example_rpo(16, 3, None).expect("Small power8 gate test with RPO failed");
+    }
+
+    #[test]
+    fn test_rpo_byte_hash_matches_miden() {
+        let bytes = b"plonky3 and winterfell";
+        assert_eq!(
+            RpoByteHash.hash_slice(bytes),
+            Rpo256::hash(bytes).as_bytes()
+        );
+    }
+}
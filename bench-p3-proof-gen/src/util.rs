@@ -0,0 +1,81 @@
+use p3_blake3::Blake3;
+use p3_field::PrimeField64;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_symmetric::CryptographicHasher;
+pub use p3_trace_convertor::{fri_params_for_security, FRI_PROOF_OF_WORK_BITS};
+
+/// Compute a deterministic digest of a trace matrix
+///
+/// The digest is Blake3 over the matrix dimensions (height, width) followed by every
+/// element's canonical `u64` value in row-major order, all encoded little-endian.
+/// Two traces share a digest only if they have the same shape and the same values,
+/// which makes it suitable for snapshotting generated traces in tests and CI.
+pub fn trace_digest<F: PrimeField64>(m: &RowMajorMatrix<F>) -> [u8; 32] {
+    let header = [m.height() as u64, m.width() as u64];
+    let bytes = header
+        .into_iter()
+        .chain(m.values.iter().map(|v| v.as_canonical_u64()))
+        .flat_map(u64::to_le_bytes);
+
+    Blake3.hash_iter(bytes)
+}
+
+/// Number of field elements in the low-degree extension of a trace
+///
+/// The LDE evaluates every column over a domain `2^log_blowup` times taller than the
+/// trace, so this is `trace_rows * trace_cols << log_blowup`. Multiply by the element
+/// size to estimate the memory the committed LDE needs before proving.
+pub fn expanded_size(trace_rows: usize, trace_cols: usize, log_blowup: usize) -> usize {
+    (trace_rows * trace_cols) << log_blowup
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field::PrimeCharacteristicRing;
+    use p3_goldilocks::Goldilocks;
+
+    use super::*;
+    use crate::{generate_trace, generate_trace_seeded};
+
+    #[test]
+    fn test_trace_digest_is_stable() {
+        let (trace_a, _) = generate_trace::<Goldilocks>(16, 4);
+        let (trace_b, _) = generate_trace::<Goldilocks>(16, 4);
+
+        assert_eq!(trace_digest(&trace_a), trace_digest(&trace_b));
+    }
+
+    #[test]
+    fn test_seeded_traces_are_byte_identical() {
+        let (trace_a, result_a) = generate_trace_seeded::<Goldilocks>(64, 8, 7);
+        let (trace_b, result_b) = generate_trace_seeded::<Goldilocks>(64, 8, 7);
+        assert_eq!(trace_digest(&trace_a), trace_digest(&trace_b));
+        assert_eq!(result_a, result_b);
+
+        let (other, _) = generate_trace_seeded::<Goldilocks>(64, 8, 8);
+        assert_ne!(trace_digest(&trace_a), trace_digest(&other));
+    }
+
+    #[test]
+    fn test_trace_digest_detects_changes() {
+        let (trace, _) = generate_trace::<Goldilocks>(16, 4);
+        let original = trace_digest(&trace);
+
+        let mut tampered = trace.clone();
+        tampered.values[5] += Goldilocks::ONE;
+        assert_ne!(original, trace_digest(&tampered));
+
+        // Same values under a different shape must not collide
+        let reshaped = RowMajorMatrix::new(trace.values.clone(), 8);
+        assert_ne!(original, trace_digest(&reshaped));
+    }
+
+    #[test]
+    fn test_expanded_size() {
+        assert_eq!(expanded_size(1 << 10, 80, 0), 80 << 10);
+        assert_eq!(expanded_size(1 << 10, 80, 1), 80 << 11);
+        assert_eq!(expanded_size(1 << 10, 80, 3), 80 << 13);
+        assert_eq!(expanded_size(1 << 19, 80, 3), 335_544_320);
+        assert_eq!(expanded_size(0, 80, 3), 0);
+    }
+}
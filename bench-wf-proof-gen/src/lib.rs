@@ -1,36 +1,58 @@
 use ark_std::{end_timer, rand::RngCore, start_timer, test_rng};
 use std::marker::PhantomData;
+use std::time::{Duration, Instant};
 use winterfell::{
     crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
-    math::{fields::f64::BaseElement, FieldElement},
+    math::{fields::f64::BaseElement, FieldElement, ToElements},
     matrix::ColMatrix,
-    Air, AirContext, Assertion, AuxRandElements, BatchingMethod, CompositionPoly,
-    CompositionPolyTrace, ConstraintCompositionCoefficients, DefaultConstraintCommitment,
-    DefaultConstraintEvaluator, DefaultTraceLde, EvaluationFrame, FieldExtension, PartitionOptions,
-    ProofOptions, Prover, StarkDomain, Trace, TraceInfo, TracePolyTable, TraceTable,
-    TransitionConstraintDegree,
+    Air, AirContext, Assertion, AuxRandElements, CompositionPoly, CompositionPolyTrace,
+    ConstraintCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator,
+    DefaultTraceLde, EvaluationFrame, PartitionOptions, Proof, ProofOptions, Prover, StarkDomain,
+    Trace, TraceInfo, TracePolyTable, TraceTable, TransitionConstraintDegree,
 };
+
+mod options;
+mod stats;
+pub use options::*;
+pub use stats::*;
+
+/// Public inputs of a [`FibLikeAir`] proof
+///
+/// The exponent is public so that the verifier builds the same constraint, with the
+/// same degree, as the prover did.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FibLikePublicInputs {
+    /// `x_1` of the last row
+    pub result: BaseElement,
+    /// Exponent of `x_1` in the main constraint
+    pub exponent: u32,
+}
+
+impl ToElements<BaseElement> for FibLikePublicInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        vec![self.result, BaseElement::from(self.exponent)]
+    }
+}
+
 pub struct FibLikeAir {
     context: AirContext<BaseElement>,
     result: BaseElement,
     num_col: usize,
+    exponent: u32,
 }
 
 impl Air for FibLikeAir {
     type BaseField = BaseElement;
-    type PublicInputs = BaseElement;
+    type PublicInputs = FibLikePublicInputs;
 
-    fn new(trace_info: TraceInfo, pub_inputs: Self::BaseField, options: ProofOptions) -> Self {
+    fn new(trace_info: TraceInfo, pub_inputs: FibLikePublicInputs, options: ProofOptions) -> Self {
         let num_col = trace_info.width();
-        let mut degrees = vec![TransitionConstraintDegree::new(8)]; // Main constraint
-        if num_col > 2 {
-            degrees.push(TransitionConstraintDegree::new(1)); // Transition constraint
-        }
-        assert_eq!(trace_info.width(), trace_info.width()); // Remove hardcoded width check
+        let degrees = Self::transition_degrees(num_col, pub_inputs.exponent);
         FibLikeAir {
             context: AirContext::new(trace_info, degrees.clone(), degrees.len(), options),
-            result: pub_inputs,
+            result: pub_inputs.result,
             num_col,
+            exponent: pub_inputs.exponent,
         }
     }
 
@@ -50,19 +72,10 @@ impl Air for FibLikeAir {
         debug_assert_eq!(self.num_col, current.len());
         debug_assert_eq!(self.num_col, next.len());
 
-        // Main constraint: x_1^8 + x_2 + ... + x_{num_col-1} = x_num_col
-        let x1_pow8 = current[0]
-            * current[0]
-            * current[0]
-            * current[0]
-            * current[0]
-            * current[0]
-            * current[0]
-            * current[0];
-
-        let mut sum = x1_pow8;
-        for i in 1..self.num_col - 1 {
-            sum = sum + current[i];
+        // Main constraint: x_1^exponent + x_2 + ... + x_{num_col-1} = x_num_col
+        let mut sum = current[0].exp(self.exponent.into());
+        for x in &current[1..self.num_col - 1] {
+            sum += *x;
         }
 
         result[0] = current[self.num_col - 1] - sum;
@@ -75,20 +88,8 @@ impl Air for FibLikeAir {
 
     fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
         let last_step = self.trace_length() - 1;
-        // For now, let's use a very permissive assertion that will likely be satisfied
-        // by computing what the constraint should produce
-        let mut rng = test_rng();
-        let first_row_values = (0..self.num_col)
-            .map(|_| BaseElement::new(rng.next_u64()))
-            .collect::<Vec<_>>();
-
-        // Compute what the last column should be
-        let x1_pow8 = first_row_values[0].exp(8u64.into());
-        let mut sum = x1_pow8;
-        for i in 1..self.num_col - 1 {
-            sum = sum + first_row_values[i];
-        }
-        let expected_last_col = sum;
+        // The prover seeds its first row from the same values
+        let expected_last_col = Self::first_row_last_col(&initial_row(self.num_col), self.exponent);
 
         vec![
             // Assert the computed constraint value in the last column of first row
@@ -98,8 +99,48 @@ impl Air for FibLikeAir {
     }
 }
 
+impl FibLikeAir {
+    /// Exponent of `x_1` in the main constraint of [`FibLikeProver::new`]'s proofs
+    pub const DEFAULT_EXPONENT: u32 = 8;
+
+    /// Declared transition degrees for `num_col` columns with `x_1^exponent`
+    ///
+    /// Winterfell sizes the composition polynomial from these, so the main constraint's
+    /// degree is the exponent; panics on an exponent of 0, which has no constraint
+    /// degree.
+    pub fn transition_degrees(num_col: usize, exponent: u32) -> Vec<TransitionConstraintDegree> {
+        assert!(exponent > 0, "the exponent of x_1 must be at least 1");
+
+        let mut degrees = vec![TransitionConstraintDegree::new(exponent as usize)]; // Main constraint
+        if num_col > 2 {
+            degrees.push(TransitionConstraintDegree::new(1)); // Transition constraint
+        }
+        degrees
+    }
+
+    /// Value of the last column for a row: `x_1^pow + x_2 + ... + x_{n-1}`
+    ///
+    /// `row` is a full trace row; its last element is the one being computed and is
+    /// ignored. This is the constraint formula shared by trace building and assertions.
+    pub fn first_row_last_col(row: &[BaseElement], pow: u32) -> BaseElement {
+        let num_col = row.len();
+        row[1..num_col - 1]
+            .iter()
+            .fold(row[0].exp(pow.into()), |sum, x| sum + *x)
+    }
+}
+
+/// Random values for the first row, from a fixed seed so assertions can be recomputed
+fn initial_row(num_col: usize) -> Vec<BaseElement> {
+    let mut rng = test_rng();
+    (0..num_col)
+        .map(|_| BaseElement::new(rng.next_u64()))
+        .collect()
+}
+
 pub struct FibLikeProver<H: ElementHasher> {
     options: ProofOptions,
+    exponent: u32,
     _hasher: PhantomData<H>,
 }
 
@@ -110,10 +151,16 @@ where
     pub fn new(options: ProofOptions) -> Self {
         Self {
             options,
+            exponent: FibLikeAir::DEFAULT_EXPONENT,
             _hasher: PhantomData,
         }
     }
 
+    /// Prove traces whose main constraint raises `x_1` to `exponent` instead
+    pub fn with_exponent(self, exponent: u32) -> Self {
+        Self { exponent, ..self }
+    }
+
     pub fn build_trace(&self, num_steps: usize, num_col: usize) -> TraceTable<BaseElement> {
         assert!(num_steps.is_power_of_two());
         assert!(num_col >= 2, "num_col must be at least 2");
@@ -124,18 +171,10 @@ where
             .collect();
 
         // Initialize first row with random values but use a fixed seed for predictable assertions
-        let mut rng = test_rng();
-        let mut current_row = (0..num_col)
-            .map(|_| BaseElement::new(rng.next_u64()))
-            .collect::<Vec<_>>();
-
-        // Compute x_num_col = x_1^8 + x_2 + ... + x_{num_col-1}
-        let x1_pow8 = current_row[0].exp(8u64.into());
-        let mut sum = x1_pow8;
-        for i in 1..num_col - 1 {
-            sum = sum + current_row[i];
-        }
-        current_row[num_col - 1] = sum;
+        let mut current_row = initial_row(num_col);
+
+        // Compute x_num_col = x_1^exponent + x_2 + ... + x_{num_col-1}
+        current_row[num_col - 1] = FibLikeAir::first_row_last_col(&current_row, self.exponent);
 
         // Add first row to columns
         for i in 0..num_col {
@@ -150,17 +189,10 @@ where
             next_row[0] = current_row[num_col - 1];
 
             // Set columns 1 to num_col-2 to 1 for simplicity
-            for i in 1..num_col - 1 {
-                next_row[i] = BaseElement::new(1);
-            }
+            next_row[1..num_col - 1].fill(BaseElement::new(1));
 
-            // x_num_col = x_1^8 + x_2 + ... + x_{num_col-1}
-            let x1_pow8 = next_row[0].exp(8u64.into());
-            let mut sum = x1_pow8;
-            for i in 1..num_col - 1 {
-                sum = sum + next_row[i];
-            }
-            next_row[num_col - 1] = sum;
+            // x_num_col = x_1^exponent + x_2 + ... + x_{num_col-1}
+            next_row[num_col - 1] = FibLikeAir::first_row_last_col(&next_row, self.exponent);
 
             // Add row to columns
             for i in 0..num_col {
@@ -191,10 +223,13 @@ where
     type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
         DefaultConstraintEvaluator<'a, Self::Air, E>;
 
-    fn get_pub_inputs(&self, trace: &Self::Trace) -> BaseElement {
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> FibLikePublicInputs {
         use winterfell::Trace;
         let last_step = trace.length() - 1;
-        trace.get(0, last_step)
+        FibLikePublicInputs {
+            result: trace.get(0, last_step),
+            exponent: self.exponent,
+        }
     }
 
     fn options(&self) -> &ProofOptions {
@@ -262,20 +297,24 @@ pub fn run_example_poseidon2(
     run_example::<miden_crypto::hash::poseidon2::Poseidon2>(num_steps, num_col)
 }
 
+pub fn run_example_keccak(
+    num_steps: usize,
+    num_col: usize,
+) -> Result<(), Box<dyn std::error::Error>> {
+    println!(
+        "Generating proof for sum constraint (x1^8 + x2 + ... + x{} = x{}) with {} steps using Keccak256 hash function",
+        num_col - 1,
+        num_col,
+        num_steps
+    );
+    run_example::<miden_crypto::hash::keccak::Keccak256>(num_steps, num_col)
+}
+
 pub fn run_example<H>(num_steps: usize, num_col: usize) -> Result<(), Box<dyn std::error::Error>>
 where
     H: ElementHasher<BaseField = BaseElement> + Sync,
 {
-    let options = ProofOptions::new(
-        100,
-        8,
-        0,
-        FieldExtension::None,
-        2,
-        1,
-        BatchingMethod::Linear,
-        BatchingMethod::Linear,
-    );
+    let options = proof_options_builder().build();
 
     let prover = FibLikeProver::<H>::new(options);
 
@@ -288,17 +327,274 @@ where
     end_timer!(timer);
     println!("Proof generated successfully!");
 
+    match verify_fiblike::<H>(proof, pub_inputs) {
+        Ok(()) => println!("Proof verified successfully!"),
+        Err(e) => println!("Proof verification failed: {:?}", e),
+    }
+
+    Ok(())
+}
+
+/// Prove one instance with hash function `H` and return the proof with its public input
+///
+/// Nothing is verified or printed; the proof is kept whole, e.g. for serialization with
+/// [`Proof::to_bytes`] and recursive verification.
+pub fn prove_returning<H>(
+    num_steps: usize,
+    num_col: usize,
+    options: ProofOptions,
+) -> Result<(Proof, FibLikePublicInputs), Box<dyn std::error::Error>>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    let prover = FibLikeProver::<H>::new(options);
+    let trace = prover.build_trace(num_steps, num_col);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    Ok((prover.prove(trace)?, pub_inputs))
+}
+
+/// [`prove_returning`] with RPO, the hash Miden's recursive verifier works over
+pub fn prove_rpo_returning(
+    num_steps: usize,
+    num_col: usize,
+    options: ProofOptions,
+) -> Result<(Proof, FibLikePublicInputs), Box<dyn std::error::Error>> {
+    prove_returning::<miden_crypto::hash::rpo::Rpo256>(num_steps, num_col, options)
+}
+
+/// Verify a [`FibLikeAir`] proof with hash function `H`
+///
+/// Only the options the proof was generated with are accepted. An exponent of 0 in
+/// `pub_inputs` is rejected up front, as [`FibLikeAir::transition_degrees`] has no
+/// degree for it.
+pub fn verify_fiblike<H>(
+    proof: Proof,
+    pub_inputs: FibLikePublicInputs,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    if pub_inputs.exponent == 0 {
+        return Err("the exponent of x_1 must be at least 1".into());
+    }
     let acceptable_options =
         winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-
-    match winterfell::verify::<FibLikeAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+    winterfell::verify::<FibLikeAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
         proof,
         pub_inputs,
         &acceptable_options,
-    ) {
-        Ok(()) => println!("Proof verified successfully!"),
-        Err(e) => println!("Proof verification failed: {:?}", e),
+    )?;
+    Ok(())
+}
+
+/// Prove and verify one instance, returning the prove and verify wall-clock times
+///
+/// Unlike [`run_example`] this is silent and fails if the proof does not verify.
+pub fn prove_and_time<H>(
+    num_steps: usize,
+    num_col: usize,
+) -> Result<(Duration, Duration), Box<dyn std::error::Error>>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    let prover = FibLikeProver::<H>::new(proof_options_builder().build());
+    let trace = prover.build_trace(num_steps, num_col);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+
+    let start = Instant::now();
+    let proof = prover.prove(trace)?;
+    let prove_time = start.elapsed();
+
+    let start = Instant::now();
+    verify_fiblike::<H>(proof, pub_inputs)?;
+    let verify_time = start.elapsed();
+
+    Ok((prove_time, verify_time))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_row_last_col_hand_computed() {
+        let row = [2u64, 3, 5, 0].map(BaseElement::new);
+        // 2^8 + 3 + 5
+        assert_eq!(
+            FibLikeAir::first_row_last_col(&row, 8),
+            BaseElement::new(264)
+        );
+        // 2^3 + 3 + 5
+        assert_eq!(
+            FibLikeAir::first_row_last_col(&row, 3),
+            BaseElement::new(16)
+        );
+        // Two columns: only x_1^pow contributes
+        let pair = [3u64, 0].map(BaseElement::new);
+        assert_eq!(
+            FibLikeAir::first_row_last_col(&pair, 4),
+            BaseElement::new(81)
+        );
     }
 
-    Ok(())
+    #[test]
+    fn test_transition_degrees_track_exponent() {
+        let degrees = FibLikeAir::transition_degrees(4, FibLikeAir::DEFAULT_EXPONENT);
+        assert_eq!(degrees[0], TransitionConstraintDegree::new(8));
+        assert_eq!(degrees.len(), 2);
+        assert_eq!(FibLikeAir::transition_degrees(2, 8).len(), 1);
+        assert_eq!(
+            FibLikeAir::transition_degrees(4, 3)[0],
+            TransitionConstraintDegree::new(3)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the exponent of x_1 must be at least 1")]
+    fn test_transition_degrees_reject_zero_exponent() {
+        FibLikeAir::transition_degrees(4, 0);
+    }
+
+    /// [`FibLikeAir`] declaring half the degree its main constraint actually has
+    struct UnderDeclaredAir(FibLikeAir);
+
+    impl Air for UnderDeclaredAir {
+        type BaseField = BaseElement;
+        type PublicInputs = FibLikePublicInputs;
+
+        fn new(
+            trace_info: TraceInfo,
+            pub_inputs: FibLikePublicInputs,
+            options: ProofOptions,
+        ) -> Self {
+            let degrees =
+                FibLikeAir::transition_degrees(trace_info.width(), pub_inputs.exponent / 2);
+            let context = AirContext::new(
+                trace_info.clone(),
+                degrees.clone(),
+                degrees.len(),
+                options.clone(),
+            );
+            Self(FibLikeAir {
+                context,
+                ..FibLikeAir::new(trace_info, pub_inputs, options)
+            })
+        }
+
+        fn context(&self) -> &AirContext<Self::BaseField> {
+            self.0.context()
+        }
+
+        fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+            &self,
+            frame: &EvaluationFrame<E>,
+            periodic_values: &[E],
+            result: &mut [E],
+        ) {
+            self.0.evaluate_transition(frame, periodic_values, result)
+        }
+
+        fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+            self.0.get_assertions()
+        }
+    }
+
+    #[test]
+    fn test_under_declared_degree_is_rejected() {
+        type H = winterfell::crypto::hashers::Blake3_256<BaseElement>;
+        let prover = FibLikeProver::<H>::new(proof_options_builder().build());
+        let trace = prover.build_trace(64, 4);
+        let pub_inputs = prover.get_pub_inputs(&trace);
+        let proof = prover.prove(trace).unwrap();
+
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+        let result = winterfell::verify::<UnderDeclaredAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof.clone(),
+            pub_inputs,
+            &acceptable_options,
+        );
+        assert!(result.is_err());
+        verify_fiblike::<H>(proof, pub_inputs).expect("declared degrees should match");
+    }
+
+    #[test]
+    fn test_verify_rejects_zero_exponent() {
+        type H = winterfell::crypto::hashers::Blake3_256<BaseElement>;
+        let prover = FibLikeProver::<H>::new(proof_options_builder().build());
+        let trace = prover.build_trace(64, 4);
+        let pub_inputs = prover.get_pub_inputs(&trace);
+        let proof = prover.prove(trace).unwrap();
+
+        let zero_exponent = FibLikePublicInputs {
+            exponent: 0,
+            ..pub_inputs
+        };
+        assert!(verify_fiblike::<H>(proof, zero_exponent).is_err());
+    }
+
+    #[test]
+    fn test_prove_and_verify_with_exponents() {
+        type H = winterfell::crypto::hashers::Blake3_256<BaseElement>;
+        for exponent in [2, 4, 8] {
+            let prover =
+                FibLikeProver::<H>::new(proof_options_builder().build()).with_exponent(exponent);
+            let trace = prover.build_trace(64, 4);
+            let pub_inputs = prover.get_pub_inputs(&trace);
+            let proof = prover.prove(trace).unwrap();
+
+            verify_fiblike::<H>(proof, pub_inputs)
+                .unwrap_or_else(|e| panic!("x_1^{exponent} proof failed: {e}"));
+        }
+    }
+
+    #[test]
+    fn test_rpo_proof_roundtrips_through_bytes() {
+        let options = proof_options_builder().queries(32).build();
+        let (proof, pub_inputs) = prove_rpo_returning(64, 4, options).unwrap();
+
+        let bytes = proof.to_bytes();
+        let decoded = Proof::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.to_bytes(), bytes);
+
+        verify_fiblike::<miden_crypto::hash::rpo::Rpo256>(decoded, pub_inputs)
+            .expect("Deserialized RPO proof should verify");
+    }
+
+    #[test]
+    fn test_prove_and_verify_with_keccak() {
+        prove_and_time::<miden_crypto::hash::keccak::Keccak256>(64, 4)
+            .expect("Keccak256 proof should verify");
+    }
+
+    #[test]
+    fn test_verify_fiblike_blake3_256() {
+        type H = winterfell::crypto::hashers::Blake3_256<BaseElement>;
+        let prover = FibLikeProver::<H>::new(proof_options_builder().build());
+        let trace = prover.build_trace(64, 4);
+        let pub_inputs = prover.get_pub_inputs(&trace);
+        let proof = prover.prove(trace).unwrap();
+
+        verify_fiblike::<H>(proof.clone(), pub_inputs).expect("Blake3_256 proof should verify");
+        let wrong_result = FibLikePublicInputs {
+            result: pub_inputs.result + BaseElement::ONE,
+            ..pub_inputs
+        };
+        assert!(verify_fiblike::<H>(proof, wrong_result).is_err());
+    }
+
+    #[test]
+    fn test_build_trace_first_row_matches_assertion() {
+        let prover = FibLikeProver::<winterfell::crypto::hashers::Blake3_256<BaseElement>>::new(
+            proof_options_builder().build(),
+        );
+        let trace = prover.build_trace(8, 4);
+        let first_row: Vec<_> = (0..4).map(|col| trace.get(col, 0)).collect();
+
+        assert_eq!(
+            trace.get(3, 0),
+            FibLikeAir::first_row_last_col(&initial_row(4), 8)
+        );
+        assert_eq!(first_row[..3], initial_row(4)[..3]);
+    }
 }
@@ -1,5 +1,11 @@
 use std::env;
-use wf::{run_example_blake256, run_example_poseidon2};
+use wf::{run_example_blake256, run_example_keccak, run_example_poseidon2};
+
+/// log2 trace heights; Winterfell requires at least 8 rows, so keep these >= 3
+const LOG_NUM_STEPS: &[usize] = &[19];
+
+/// Numbers of main-trace columns in the Winterfell AIR
+const NUM_COLS: &[usize] = &[80];
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Get number of threads from environment or use default
@@ -36,23 +42,27 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Transition: next_x1 = current_x_num_col");
     println!();
 
-    for &log_num_steps in [19].iter() {
+    for &log_num_steps in LOG_NUM_STEPS {
         let num_steps = 1 << log_num_steps;
-        for num_col in [80].iter() {
+        for &num_col in NUM_COLS {
             println!("Number of steps: {}, Columns: {}", num_steps, num_col);
 
             match hash_type.as_str() {
                 "blake256" => {
                     println!("Running with Blake3_256 hash function");
-                    run_example_blake256(num_steps, *num_col)?;
+                    run_example_blake256(num_steps, num_col)?;
+                }
+                "keccak" => {
+                    println!("Running with Keccak256 hash function");
+                    run_example_keccak(num_steps, num_col)?;
                 }
                 "poseidon2" => {
                     println!("Running with Poseidon2 hash function");
-                    run_example_poseidon2(num_steps, *num_col)?;
+                    run_example_poseidon2(num_steps, num_col)?;
                 }
                 _ => {
                     println!("Running with Blake3_256 hash function");
-                    run_example_blake256(num_steps, *num_col)?;
+                    run_example_blake256(num_steps, num_col)?;
                 }
             }
         }
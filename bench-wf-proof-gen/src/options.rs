@@ -0,0 +1,152 @@
+use winterfell::{BatchingMethod, FieldExtension, ProofOptions};
+
+/// Start building [`ProofOptions`] from the parameters used by [`crate::run_example`]
+pub fn proof_options_builder() -> ProofOptionsBuilder {
+    ProofOptionsBuilder::default()
+}
+
+/// Named-setter builder for Winterfell's [`ProofOptions`]
+///
+/// Range checks are left to [`ProofOptions::new`], which panics on invalid values.
+#[derive(Clone, Debug)]
+pub struct ProofOptionsBuilder {
+    queries: usize,
+    blowup: usize,
+    grinding: u32,
+    field_extension: FieldExtension,
+    fri_folding_factor: usize,
+    fri_remainder_max_degree: usize,
+    constraint_batching: BatchingMethod,
+    deep_batching: BatchingMethod,
+}
+
+impl Default for ProofOptionsBuilder {
+    fn default() -> Self {
+        Self {
+            queries: 100,
+            blowup: 8,
+            grinding: 0,
+            field_extension: FieldExtension::None,
+            fri_folding_factor: 2,
+            fri_remainder_max_degree: 1,
+            constraint_batching: BatchingMethod::Linear,
+            deep_batching: BatchingMethod::Linear,
+        }
+    }
+}
+
+impl ProofOptionsBuilder {
+    /// Number of FRI queries (1..=255); soundness grows with `queries * log2(blowup)`
+    pub fn queries(mut self, queries: usize) -> Self {
+        self.queries = queries;
+        self
+    }
+
+    /// LDE blowup factor: a power of two in 2..=128, at least the constraint degree
+    pub fn blowup(mut self, blowup: usize) -> Self {
+        self.blowup = blowup;
+        self
+    }
+
+    /// Proof-of-work bits required before query positions are drawn (0..=32)
+    pub fn grinding(mut self, grinding: u32) -> Self {
+        self.grinding = grinding;
+        self
+    }
+
+    /// Extension field used for composition and DEEP randomness
+    pub fn field_extension(mut self, field_extension: FieldExtension) -> Self {
+        self.field_extension = field_extension;
+        self
+    }
+
+    /// Arity of each FRI folding step: 2, 4, 8 or 16
+    pub fn fri_folding_factor(mut self, fri_folding_factor: usize) -> Self {
+        self.fri_folding_factor = fri_folding_factor;
+        self
+    }
+
+    /// Maximum degree of the FRI remainder polynomial; one less than a power of two, at most 255
+    pub fn fri_remainder_max_degree(mut self, fri_remainder_max_degree: usize) -> Self {
+        self.fri_remainder_max_degree = fri_remainder_max_degree;
+        self
+    }
+
+    /// How constraint evaluations are combined into the composition polynomial
+    pub fn constraint_batching(mut self, constraint_batching: BatchingMethod) -> Self {
+        self.constraint_batching = constraint_batching;
+        self
+    }
+
+    /// How trace and composition openings are combined into the DEEP polynomial
+    pub fn deep_batching(mut self, deep_batching: BatchingMethod) -> Self {
+        self.deep_batching = deep_batching;
+        self
+    }
+
+    pub fn build(self) -> ProofOptions {
+        ProofOptions::new(
+            self.queries,
+            self.blowup,
+            self.grinding,
+            self.field_extension,
+            self.fri_folding_factor,
+            self.fri_remainder_max_degree,
+            self.constraint_batching,
+            self.deep_batching,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_reproduces_example_options() {
+        let expected = ProofOptions::new(
+            100,
+            8,
+            0,
+            FieldExtension::None,
+            2,
+            1,
+            BatchingMethod::Linear,
+            BatchingMethod::Linear,
+        );
+
+        assert_eq!(proof_options_builder().build(), expected);
+        assert_eq!(
+            proof_options_builder()
+                .blowup(8)
+                .queries(100)
+                .grinding(0)
+                .field_extension(FieldExtension::None)
+                .fri_folding_factor(2)
+                .fri_remainder_max_degree(1)
+                .constraint_batching(BatchingMethod::Linear)
+                .deep_batching(BatchingMethod::Linear)
+                .build(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_builder_setters_change_options() {
+        let options = proof_options_builder()
+            .queries(28)
+            .blowup(4)
+            .grinding(16)
+            .field_extension(FieldExtension::Quadratic)
+            .fri_folding_factor(4)
+            .fri_remainder_max_degree(31)
+            .build();
+
+        assert_eq!(options.num_queries(), 28);
+        assert_eq!(options.blowup_factor(), 4);
+        assert_eq!(options.grinding_factor(), 16);
+        assert_eq!(options.field_extension(), FieldExtension::Quadratic);
+        assert_eq!(options.to_fri_options().folding_factor(), 4);
+        assert_eq!(options.to_fri_options().remainder_max_degree(), 31);
+    }
+}
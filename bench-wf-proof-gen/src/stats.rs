@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use winterfell::crypto::ElementHasher;
+use winterfell::math::fields::f64::BaseElement;
+
+use crate::prove_and_time;
+
+/// Summary of repeated timings of one phase
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimingStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    /// Population standard deviation
+    pub stddev: Duration,
+}
+
+impl TimingStats {
+    /// Summarize a non-empty set of samples; the median of an even count is the lower one
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "need at least one timing sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        Self {
+            min: sorted[0],
+            median: sorted[(sorted.len() - 1) / 2],
+            max: sorted[sorted.len() - 1],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Prove and verify timings over repeated runs
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProvingStats {
+    pub runs: usize,
+    pub prove: TimingStats,
+    pub verify: TimingStats,
+}
+
+/// Prove and verify the same instance `runs` times and summarize the timings
+///
+/// A single `start_timer!` measurement is easily skewed by a noisy machine; the
+/// median over several runs is a steadier number to compare between commits.
+pub fn bench_proving_stats<H>(
+    num_steps: usize,
+    num_col: usize,
+    runs: usize,
+) -> Result<ProvingStats, Box<dyn std::error::Error>>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    assert!(runs > 0, "need at least one run");
+
+    let mut prove_times = Vec::with_capacity(runs);
+    let mut verify_times = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let (prove_time, verify_time) = prove_and_time::<H>(num_steps, num_col)?;
+        prove_times.push(prove_time);
+        verify_times.push(verify_time);
+    }
+
+    Ok(ProvingStats {
+        runs,
+        prove: TimingStats::from_samples(&prove_times),
+        verify: TimingStats::from_samples(&verify_times),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use winterfell::crypto::hashers::Blake3_256;
+
+    use super::*;
+
+    fn assert_ordered(stats: &TimingStats) {
+        assert!(stats.min <= stats.median);
+        assert!(stats.median <= stats.max);
+    }
+
+    #[test]
+    fn test_bench_proving_stats_are_ordered() {
+        let stats = bench_proving_stats::<Blake3_256<BaseElement>>(64, 3, 3).unwrap();
+
+        assert_eq!(stats.runs, 3);
+        assert_ordered(&stats.prove);
+        assert_ordered(&stats.verify);
+    }
+
+    #[test]
+    fn test_timing_stats_from_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = TimingStats::from_samples(&samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(2));
+        assert_eq!(stats.max, Duration::from_millis(4));
+        // Population stddev of 1..=4 ms is sqrt(1.25) ms
+        let expected = 1.25f64.sqrt() / 1000.0;
+        assert!((stats.stddev.as_secs_f64() - expected).abs() < 1e-9);
+    }
+}
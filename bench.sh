@@ -82,6 +82,12 @@ NUM_THREADS=16 HASH_TYPE=blake3 cargo run --release --bin p3_demo > 16_thread_p3
 # # NUM_THREADS=8 HASH_TYPE=blake256 cargo run --release --bin wf_demo > 8_thread_wf_blake256.log
 # NUM_THREADS=16 HASH_TYPE=blake256 cargo run --release --bin wf_demo > 16_thread_wf_blake256.log
 
+# ######################################
+# # WF demos with Keccak256 hash function
+# ######################################
+# NUM_THREADS=1 HASH_TYPE=keccak cargo run --release --bin wf_demo > 1_thread_wf_keccak.log
+# NUM_THREADS=16 HASH_TYPE=keccak cargo run --release --bin wf_demo > 16_thread_wf_keccak.log
+
 # ######################################
 # # WF demos with Poseidon2 hash function (slow)
 # ######################################
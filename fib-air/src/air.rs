@@ -0,0 +1,94 @@
+use p3_air::{Air, AirBuilder, AirBuilderWithPublicValues, BaseAir, BaseAirWithPublicValues};
+use p3_field::{Field, PrimeCharacteristicRing};
+use p3_matrix::Matrix;
+
+/// Exponent of `x_1` in [`FibLikeAir`]'s main constraint used by
+/// [`generate_trace`](crate::generate_trace)
+pub const DEFAULT_EXPONENT: u32 = 8;
+
+/// `x_1^exponent + x_2 + ... + x_{num_col-1} = x_num_col`, with each row's `x_1` the
+/// previous row's `x_num_col`
+///
+/// The last row's `x_1` is the single public value, so a proof binds the claimed final
+/// result. The AIR is the same over every field.
+#[derive(Clone)]
+pub struct FibLikeAir {
+    pub num_col: usize,
+    /// Exponent of `x_1` in the main constraint, which is also the constraint's degree
+    pub exponent: u32,
+}
+
+impl<F> BaseAir<F> for FibLikeAir {
+    fn width(&self) -> usize {
+        self.num_col
+    }
+}
+
+impl<F> BaseAirWithPublicValues<F> for FibLikeAir {
+    fn num_public_values(&self) -> usize {
+        1
+    }
+}
+
+impl<AB: AirBuilderWithPublicValues> Air<AB> for FibLikeAir {
+    fn eval(&self, builder: &mut AB) {
+        let final_result = builder.public_values()[0];
+        let main = builder.main();
+        let local = main.row_slice(0).expect("Matrix is empty?");
+        let next = main.row_slice(1).expect("Matrix only has 1 row?");
+
+        // Get all local variables
+        let x1 = local[0];
+
+        // Constraint: x_1^exponent + x_2 + ... + x_{num_col-1} = x_num_col
+        let mut sum = pow(x1.into(), self.exponent);
+
+        // Add x_2 through x_{num_col-1}
+        for x in &local[1..self.num_col - 1] {
+            sum += *x;
+        }
+
+        // Assert sum equals x_num_col (last column)
+        builder.assert_zero(sum - local[self.num_col - 1]);
+
+        // Transition constraint: next_x1 = current x_num_col
+        let next_x1 = next[0];
+        builder
+            .when_transition()
+            .assert_eq(next_x1, local[self.num_col - 1]);
+
+        // Boundary constraint: the last row's x_1 is the claimed final result
+        builder.when_last_row().assert_eq(local[0], final_result);
+
+        // No initial constraints needed - allowing random starting values
+    }
+}
+
+/// `base^exponent` by square-and-multiply, so the expression has `log2(exponent)`
+/// squarings rather than `exponent - 1` multiplications
+fn pow<E: PrimeCharacteristicRing + Clone>(base: E, exponent: u32) -> E {
+    let mut power: Option<E> = None;
+    let mut square = base;
+    let mut remaining = exponent;
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            power = Some(match power {
+                Some(power) => power * square.clone(),
+                None => square.clone(),
+            });
+        }
+        remaining >>= 1;
+        if remaining > 0 {
+            square = square.clone() * square;
+        }
+    }
+    power.unwrap_or(E::ONE)
+}
+
+/// `x_1^exponent + x_2 + ... + x_{num_col-1}`, the value the last column of `row` must hold
+pub fn last_column<F: Field>(row: &[F], exponent: u32) -> F {
+    let num_col = row.len();
+    row[1..num_col - 1]
+        .iter()
+        .fold(row[0].exp_u64(exponent as u64), |sum, x| sum + *x)
+}
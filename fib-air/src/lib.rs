@@ -0,0 +1,18 @@
+//! The `FibLikeAir` benchmark circuit shared by the Plonky3 provers
+//!
+//! Every row satisfies `x_1^exponent + x_2 + ... + x_{num_col-1} = x_num_col`, and each
+//! row's `x_1` is the previous row's `x_num_col`. The `p3`, `p3-monty` and
+//! `wasm-p3-proof-gen` crates prove this same AIR over their own fields and hashes.
+
+#![no_std]
+
+extern crate alloc;
+
+mod air;
+pub use air::*;
+
+mod symbolic;
+pub use symbolic::*;
+
+mod trace;
+pub use trace::*;
@@ -0,0 +1,82 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use p3_air::BaseAirWithPublicValues;
+use p3_field::Field;
+use p3_uni_stark::{get_symbolic_constraints, Entry, SymbolicExpression, SymbolicVariable};
+
+use crate::FibLikeAir;
+
+impl FibLikeAir {
+    /// Every constraint of this AIR over `F` rendered as a polynomial string
+    ///
+    /// The constraints are evaluated with Plonky3's symbolic builder, so two AIR
+    /// definitions that encode the same polynomials render identically.
+    pub fn symbolic_constraints<F: Field>(&self) -> Vec<String> {
+        let num_public_values = BaseAirWithPublicValues::<F>::num_public_values(self);
+        get_symbolic_constraints::<F, _>(self, 0, num_public_values)
+            .iter()
+            .map(render_symbolic)
+            .collect()
+    }
+}
+
+/// Fully parenthesized infix form of a symbolic expression
+///
+/// Main trace cells render as `local[i]` and `next[i]`.
+pub fn render_symbolic<F: Field>(expr: &SymbolicExpression<F>) -> String {
+    match expr {
+        SymbolicExpression::Variable(var) => render_variable(var),
+        SymbolicExpression::IsFirstRow => "is_first_row".to_string(),
+        SymbolicExpression::IsLastRow => "is_last_row".to_string(),
+        SymbolicExpression::IsTransition => "is_transition".to_string(),
+        SymbolicExpression::Constant(c) => c.to_string(),
+        SymbolicExpression::Add { x, y, .. } => {
+            format!("({} + {})", render_symbolic(x), render_symbolic(y))
+        }
+        SymbolicExpression::Sub { x, y, .. } => {
+            format!("({} - {})", render_symbolic(x), render_symbolic(y))
+        }
+        SymbolicExpression::Neg { x, .. } => format!("-{}", render_symbolic(x)),
+        SymbolicExpression::Mul { x, y, .. } => {
+            format!("({} * {})", render_symbolic(x), render_symbolic(y))
+        }
+    }
+}
+
+fn render_variable<F>(var: &SymbolicVariable<F>) -> String {
+    let index = var.index;
+    match var.entry {
+        Entry::Main { offset: 0 } => format!("local[{index}]"),
+        Entry::Main { offset: 1 } => format!("next[{index}]"),
+        Entry::Main { offset } => format!("main[{offset}][{index}]"),
+        Entry::Preprocessed { offset } => format!("preprocessed[{offset}][{index}]"),
+        Entry::Permutation { offset } => format!("permutation[{offset}][{index}]"),
+        Entry::Public => format!("public[{index}]"),
+        Entry::Challenge => format!("challenge[{index}]"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_goldilocks::Goldilocks;
+
+    use super::*;
+    use crate::DEFAULT_EXPONENT;
+
+    #[test]
+    fn test_symbolic_constraints_render() {
+        let air = FibLikeAir {
+            num_col: 3,
+            exponent: DEFAULT_EXPONENT,
+        };
+        let constraints = air.symbolic_constraints::<Goldilocks>();
+
+        assert_eq!(constraints.len(), 3);
+        assert!(constraints[0].contains("local[0]"));
+        assert!(constraints[0].contains("local[2]"));
+        assert_eq!(constraints[1], "(is_transition * (next[0] - local[2]))");
+        assert_eq!(constraints[2], "(is_last_row * (local[0] - public[0]))");
+    }
+}
@@ -0,0 +1,212 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use p3_field::Field;
+use p3_matrix::dense::RowMajorMatrix;
+use rand::{rngs::SmallRng, RngCore, SeedableRng};
+
+use crate::{last_column, DEFAULT_EXPONENT};
+
+/// Seed of the random first row in the `p3` and `p3-monty` provers' traces
+pub const DEFAULT_SEED: u64 = 123;
+
+/// First row of `num_col` random `u32`s drawn from `seed`
+///
+/// The last column is overwritten by [`trace_rows`] to satisfy the main constraint.
+pub fn random_first_row<F: Field>(num_col: usize, seed: u64) -> Vec<F> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    (0..num_col).map(|_| F::from_u32(rng.next_u32())).collect()
+}
+
+/// First row `x_i = i + 1`, the deterministic start of the wasm prover's traces
+pub fn sequential_first_row<F: Field>(num_col: usize) -> Vec<F> {
+    (0..num_col).map(|i| F::from_u32((i + 1) as u32)).collect()
+}
+
+/// Generate the trace one row at a time, starting from `first_row`
+///
+/// The first row's last column is fixed so the row satisfies the main constraint;
+/// later rows take `x_1` from the previous row's last column and set `x_2` through
+/// `x_{num_col-1}` to 1. Only the current row is kept alive, so a 2^20-row trace never
+/// has to be held in memory by the generator.
+pub fn trace_rows<F: Field>(
+    mut first_row: Vec<F>,
+    num_steps: usize,
+    exponent: u32,
+) -> impl Iterator<Item = Vec<F>> {
+    let num_col = first_row.len();
+    assert!(num_steps.is_power_of_two());
+    assert!(num_col >= 2, "num_col must be at least 2");
+
+    first_row[num_col - 1] = last_column(&first_row, exponent);
+    let mut row = first_row;
+
+    (0..num_steps).map(move |step| {
+        if step > 0 {
+            // x_1 of next row = x_num_col of current row, columns 1 to num_col-2 are 1
+            let mut next_row = vec![F::ONE; num_col];
+            next_row[0] = row[num_col - 1];
+            next_row[num_col - 1] = last_column(&next_row, exponent);
+            row = next_row;
+        }
+        row.clone()
+    })
+}
+
+/// [`trace_rows`] collected into a matrix, with the last row's `x_1` as final result
+pub fn trace_from_first_row<F: Field>(
+    first_row: Vec<F>,
+    num_steps: usize,
+    exponent: u32,
+) -> (RowMajorMatrix<F>, F) {
+    let num_col = first_row.len();
+    let mut values = Vec::with_capacity(num_steps * num_col);
+    for row in trace_rows(first_row, num_steps, exponent) {
+        values.extend_from_slice(&row);
+    }
+
+    let final_result = values[values.len() - num_col]; // First element of last row
+    (RowMajorMatrix::new(values, num_col), final_result)
+}
+
+/// Trace satisfying a [`FibLikeAir`](crate::FibLikeAir) with [`DEFAULT_EXPONENT`],
+/// starting from a random first row drawn from `seed`
+pub fn generate_trace<F: Field>(
+    num_steps: usize,
+    num_col: usize,
+    seed: u64,
+) -> (RowMajorMatrix<F>, F) {
+    generate_trace_with_exponent(num_steps, num_col, seed, DEFAULT_EXPONENT)
+}
+
+/// [`generate_trace`] for a [`FibLikeAir`](crate::FibLikeAir) raising `x_1` to `exponent`
+pub fn generate_trace_with_exponent<F: Field>(
+    num_steps: usize,
+    num_col: usize,
+    seed: u64,
+    exponent: u32,
+) -> (RowMajorMatrix<F>, F) {
+    trace_from_first_row(random_first_row(num_col, seed), num_steps, exponent)
+}
+
+/// Trace satisfying a [`FibLikeAir`](crate::FibLikeAir) with [`DEFAULT_EXPONENT`],
+/// starting from [`sequential_first_row`]
+pub fn generate_sequential_trace<F: Field>(
+    num_steps: usize,
+    num_col: usize,
+) -> (RowMajorMatrix<F>, F) {
+    trace_from_first_row(sequential_first_row(num_col), num_steps, DEFAULT_EXPONENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_field::{PrimeCharacteristicRing, PrimeField64};
+    use p3_goldilocks::Goldilocks;
+
+    use super::*;
+
+    /// The trace generator the `p3` and `p3-monty` crates each carried, for any field
+    fn legacy_random_trace<F: Field>(num_steps: usize, num_col: usize) -> (Vec<F>, F) {
+        let mut rng = SmallRng::seed_from_u64(123);
+        let mut values = Vec::with_capacity(num_steps * num_col);
+
+        let mut current_row = (0..num_col)
+            .map(|_| F::from_u32(rng.next_u32()))
+            .collect::<Vec<_>>();
+        let mut sum = current_row[0].exp_u64(8);
+        for x in &current_row[1..num_col - 1] {
+            sum += *x;
+        }
+        current_row[num_col - 1] = sum;
+
+        for step in 0..num_steps {
+            values.extend_from_slice(&current_row);
+            if step < num_steps - 1 {
+                let mut next_row = vec![F::ZERO; num_col];
+                next_row[0] = current_row[num_col - 1];
+                next_row[1..num_col - 1].fill(F::ONE);
+                let mut sum = next_row[0].exp_u64(8);
+                for x in &next_row[1..num_col - 1] {
+                    sum += *x;
+                }
+                next_row[num_col - 1] = sum;
+                current_row = next_row;
+            }
+        }
+
+        let final_result = values[values.len() - num_col];
+        (values, final_result)
+    }
+
+    /// The trace generator the wasm crate carried
+    fn legacy_sequential_trace(num_steps: usize, num_col: usize) -> (Vec<Goldilocks>, Goldilocks) {
+        let mut values = Vec::with_capacity(num_steps * num_col);
+        let mut row = (0..num_col)
+            .map(|i| Goldilocks::from_u32((i + 1) as u32))
+            .collect::<Vec<_>>();
+        row[num_col - 1] = last_column(&row, 8);
+
+        for step in 0..num_steps {
+            if step > 0 {
+                let mut next_row = vec![Goldilocks::ONE; num_col];
+                next_row[0] = row[num_col - 1];
+                next_row[num_col - 1] = last_column(&next_row, 8);
+                row = next_row;
+            }
+            values.extend_from_slice(&row);
+        }
+
+        let final_result = values[values.len() - num_col];
+        (values, final_result)
+    }
+
+    #[test]
+    fn test_random_trace_matches_p3() {
+        for (num_steps, num_col) in [(4, 2), (16, 3), (64, 8)] {
+            let (trace, final_result) =
+                generate_trace::<Goldilocks>(num_steps, num_col, DEFAULT_SEED);
+            let (values, expected_result) = legacy_random_trace(num_steps, num_col);
+            assert_eq!(trace.values, values);
+            assert_eq!(final_result, expected_result);
+        }
+    }
+
+    #[test]
+    fn test_random_trace_matches_p3_monty() {
+        type MontyGoldilocks = p3_goldilocks_monty::Goldilocks;
+
+        for (num_steps, num_col) in [(4, 2), (16, 3), (64, 8)] {
+            let (trace, final_result) =
+                generate_trace::<MontyGoldilocks>(num_steps, num_col, DEFAULT_SEED);
+            let (values, expected_result) = legacy_random_trace(num_steps, num_col);
+            assert_eq!(trace.values, values);
+            assert_eq!(final_result, expected_result);
+
+            // Both Goldilocks representations produce the same canonical trace
+            let (standard, _) = generate_trace::<Goldilocks>(num_steps, num_col, DEFAULT_SEED);
+            assert!(trace
+                .values
+                .iter()
+                .zip(&standard.values)
+                .all(|(m, s)| m.as_canonical_u64() == s.as_canonical_u64()));
+        }
+    }
+
+    #[test]
+    fn test_sequential_trace_matches_wasm() {
+        for (num_steps, num_col) in [(4, 2), (16, 3), (64, 8)] {
+            let (trace, final_result) = generate_sequential_trace::<Goldilocks>(num_steps, num_col);
+            let (values, expected_result) = legacy_sequential_trace(num_steps, num_col);
+            assert_eq!(trace.values, values);
+            assert_eq!(final_result, expected_result);
+        }
+    }
+
+    #[test]
+    fn test_seed_changes_only_the_random_start() {
+        let (a, _) = generate_trace::<Goldilocks>(16, 4, 1);
+        let (b, _) = generate_trace::<Goldilocks>(16, 4, 2);
+        assert_ne!(a.values[..4], b.values[..4]);
+        assert_eq!(a, generate_trace::<Goldilocks>(16, 4, 1).0);
+    }
+}
@@ -0,0 +1,86 @@
+//! Merkle commitment with Poseidon2 over Goldilocks vs Goldilocks-Montgomery
+//!
+//! `poseidon_comparison.rs` times permutations and a hand-rolled tree of 4-element
+//! leaves. This commits a full width-80 matrix with `MerkleTreeMmcs`, as the prover
+//! does for the trace, so row hashing through the sponge and tree construction are
+//! included in the comparison.
+//!
+//! ## Running Benchmarks
+//!
+//! ```bash
+//! cargo bench -p p3-goldilocks-monty --bench merkle_commit
+//! ```
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use p3_commit::Mmcs;
+use p3_field::{Field, PrimeCharacteristicRing};
+use p3_goldilocks::{Goldilocks as GoldilocksStd, Poseidon2Goldilocks as Poseidon2Std};
+use p3_goldilocks_monty::{Goldilocks as GoldilocksMonty, Poseidon2Goldilocks as Poseidon2Monty};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_symmetric::{CryptographicPermutation, PaddingFreeSponge, TruncatedPermutation};
+use rand::rngs::SmallRng;
+use rand::{RngCore, SeedableRng};
+
+const LOG_ROWS: usize = 14;
+const WIDTH: usize = 80;
+
+const P: u64 = 0xffff_ffff_0000_0001;
+
+type PoseidonMmcs<F, Perm> = MerkleTreeMmcs<
+    F,
+    F,
+    PaddingFreeSponge<Perm, 16, 8, 4>,
+    TruncatedPermutation<Perm, 2, 4, 16>,
+    4,
+>;
+
+/// The same canonical values as a matrix over `F`
+fn matrix<F: PrimeCharacteristicRing + Copy + Send + Sync>(raw: &[u64]) -> RowMajorMatrix<F> {
+    RowMajorMatrix::new(raw.iter().map(|&x| F::from_u64(x)).collect(), WIDTH)
+}
+
+fn mmcs<F, Perm>(perm: Perm) -> PoseidonMmcs<F, Perm>
+where
+    F: Field,
+    Perm: CryptographicPermutation<[F; 16]>,
+{
+    MerkleTreeMmcs::new(
+        PaddingFreeSponge::new(perm.clone()),
+        TruncatedPermutation::new(perm),
+    )
+}
+
+fn bench_merkle_commit(c: &mut Criterion) {
+    let mut rng = SmallRng::seed_from_u64(42);
+    let raw: Vec<u64> = (0..WIDTH << LOG_ROWS).map(|_| rng.next_u64() % P).collect();
+
+    let std_mmcs = mmcs::<GoldilocksStd, _>(Poseidon2Std::<16>::new_from_rng_128(&mut rng));
+    let monty_mmcs = mmcs::<GoldilocksMonty, _>(Poseidon2Monty::<16>::new_from_rng_128(&mut rng));
+    let std_matrix = matrix::<GoldilocksStd>(&raw);
+    let monty_matrix = matrix::<GoldilocksMonty>(&raw);
+
+    let mut group = c.benchmark_group(format!("poseidon2_merkle_commit_2^{LOG_ROWS}x{WIDTH}"));
+    group.sample_size(10);
+
+    group.bench_function("goldilocks", |b| {
+        b.iter_batched(
+            || std_matrix.clone(),
+            |m| std_mmcs.commit_matrix(m),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function("goldilocks_monty", |b| {
+        b.iter_batched(
+            || monty_matrix.clone(),
+            |m| monty_mmcs.commit_matrix(m),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_merkle_commit);
+criterion_main!(benches);
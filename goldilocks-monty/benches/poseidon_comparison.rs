@@ -15,6 +15,14 @@
 //! ```bash
 //! RUSTFLAGS="-C target-cpu=native" cargo bench --bench poseidon_comparison
 //! ```
+//!
+//! ## Inputs
+//!
+//! Both implementations permute the same canonical values. They are drawn as `u64`s
+//! and converted to each field's internal representation once, before the timed loop,
+//! so the Montgomery variant is measured on `permute_mut` alone. (`rng.random()` would
+//! also avoid conversion, since it samples the Montgomery form directly, but then the
+//! two fields would not see the same values.)
 
 use core::array;
 use std::hint::black_box;
@@ -35,17 +43,35 @@ use p3_goldilocks_monty::{
 use p3_poseidon2::{ExternalLayerConstants, Poseidon2};
 use p3_symmetric::Permutation;
 use rand::rngs::SmallRng;
-use rand::{Rng, SeedableRng};
+use rand::{RngCore, SeedableRng};
+
+const P: u64 = 0xffff_ffff_0000_0001;
 
 const WIDTH: usize = 8;
 
+/// Canonical field values shared by both benchmarks
+fn canonical_inputs<const N: usize>(rng: &mut SmallRng, count: usize) -> Vec<[u64; N]> {
+    (0..count)
+        .map(|_| array::from_fn(|_| rng.next_u64() % P))
+        .collect()
+}
+
+fn std_inputs<const N: usize>(raw: &[[u64; N]]) -> Vec<[GoldilocksStd; N]> {
+    raw.iter().map(|r| r.map(GoldilocksStd::from_u64)).collect()
+}
+
+/// Converted to Montgomery form here, outside any timed loop
+fn monty_inputs<const N: usize>(raw: &[[u64; N]]) -> Vec<[GoldilocksMonty; N]> {
+    raw.iter().map(|&r| GoldilocksMonty::new_array(r)).collect()
+}
+
 fn bench_poseidon2_std_single(c: &mut Criterion) {
     let mut rng = SmallRng::seed_from_u64(42);
 
     let poseidon2: Poseidon2GoldilocksHLStd<WIDTH> = Poseidon2::new(
         ExternalLayerConstants::<GoldilocksStd, WIDTH>::new_from_saved_array(
             HL_STD_EXT_CONSTANTS,
-            |arr| arr.map(|x| GoldilocksStd::from_u64(x)),
+            |arr| arr.map(GoldilocksStd::from_u64),
         ),
         HL_STD_INT_CONSTANTS
             .iter()
@@ -54,7 +80,7 @@ fn bench_poseidon2_std_single(c: &mut Criterion) {
     );
 
     // Precompute input
-    let input: [GoldilocksStd; WIDTH] = array::from_fn(|_| rng.random::<GoldilocksStd>());
+    let input = std_inputs::<WIDTH>(&canonical_inputs(&mut rng, 1))[0];
 
     c.bench_function("poseidon2_std_single", |b| {
         b.iter(|| {
@@ -71,7 +97,7 @@ fn bench_poseidon2_monty_single(c: &mut Criterion) {
     let poseidon2: Poseidon2GoldilocksHLMonty<WIDTH> = Poseidon2::new(
         ExternalLayerConstants::<GoldilocksMonty, WIDTH>::new_from_saved_array(
             HL_MONTY_EXT_CONSTANTS,
-            |arr| arr.map(|x| GoldilocksMonty::from_u64(x)),
+            |arr| arr.map(GoldilocksMonty::from_u64),
         ),
         HL_MONTY_INT_CONSTANTS
             .iter()
@@ -80,7 +106,7 @@ fn bench_poseidon2_monty_single(c: &mut Criterion) {
     );
 
     // Precompute input
-    let input: [GoldilocksMonty; WIDTH] = array::from_fn(|_| rng.random::<GoldilocksMonty>());
+    let input = monty_inputs::<WIDTH>(&canonical_inputs(&mut rng, 1))[0];
 
     c.bench_function("poseidon2_monty_single", |b| {
         b.iter(|| {
@@ -98,7 +124,7 @@ fn bench_poseidon2_std_batch(c: &mut Criterion) {
     let poseidon2: Poseidon2GoldilocksHLStd<WIDTH> = Poseidon2::new(
         ExternalLayerConstants::<GoldilocksStd, WIDTH>::new_from_saved_array(
             HL_STD_EXT_CONSTANTS,
-            |arr| arr.map(|x| GoldilocksStd::from_u64(x)),
+            |arr| arr.map(GoldilocksStd::from_u64),
         ),
         HL_STD_INT_CONSTANTS
             .iter()
@@ -107,9 +133,7 @@ fn bench_poseidon2_std_batch(c: &mut Criterion) {
     );
 
     // Precompute all inputs
-    let inputs: Vec<[GoldilocksStd; WIDTH]> = (0..BATCH_SIZE)
-        .map(|_| array::from_fn(|_| rng.random::<GoldilocksStd>()))
-        .collect();
+    let inputs = std_inputs::<WIDTH>(&canonical_inputs(&mut rng, BATCH_SIZE));
 
     c.bench_function("poseidon2_std_batch_1000", |b| {
         b.iter(|| {
@@ -131,7 +155,7 @@ fn bench_poseidon2_monty_batch(c: &mut Criterion) {
     let poseidon2: Poseidon2GoldilocksHLMonty<WIDTH> = Poseidon2::new(
         ExternalLayerConstants::<GoldilocksMonty, WIDTH>::new_from_saved_array(
             HL_MONTY_EXT_CONSTANTS,
-            |arr| arr.map(|x| GoldilocksMonty::from_u64(x)),
+            |arr| arr.map(GoldilocksMonty::from_u64),
         ),
         HL_MONTY_INT_CONSTANTS
             .iter()
@@ -140,9 +164,7 @@ fn bench_poseidon2_monty_batch(c: &mut Criterion) {
     );
 
     // Precompute all inputs
-    let inputs: Vec<[GoldilocksMonty; WIDTH]> = (0..BATCH_SIZE)
-        .map(|_| array::from_fn(|_| rng.random::<GoldilocksMonty>()))
-        .collect();
+    let inputs = monty_inputs::<WIDTH>(&canonical_inputs(&mut rng, BATCH_SIZE));
 
     c.bench_function("poseidon2_monty_batch_1000", |b| {
         b.iter(|| {
@@ -164,7 +186,7 @@ fn bench_poseidon2_std_throughput(c: &mut Criterion) {
     let poseidon2: Poseidon2GoldilocksHLStd<WIDTH> = Poseidon2::new(
         ExternalLayerConstants::<GoldilocksStd, WIDTH>::new_from_saved_array(
             HL_STD_EXT_CONSTANTS,
-            |arr| arr.map(|x| GoldilocksStd::from_u64(x)),
+            |arr| arr.map(GoldilocksStd::from_u64),
         ),
         HL_STD_INT_CONSTANTS
             .iter()
@@ -173,9 +195,7 @@ fn bench_poseidon2_std_throughput(c: &mut Criterion) {
     );
 
     // Precompute all inputs
-    let inputs: Vec<[GoldilocksStd; WIDTH]> = (0..ARRAY_SIZE)
-        .map(|_| array::from_fn(|_| rng.random::<GoldilocksStd>()))
-        .collect();
+    let inputs = std_inputs::<WIDTH>(&canonical_inputs(&mut rng, ARRAY_SIZE));
 
     c.bench_function("poseidon2_std_throughput_10k", |b| {
         b.iter(|| {
@@ -197,7 +217,7 @@ fn bench_poseidon2_monty_throughput(c: &mut Criterion) {
     let poseidon2: Poseidon2GoldilocksHLMonty<WIDTH> = Poseidon2::new(
         ExternalLayerConstants::<GoldilocksMonty, WIDTH>::new_from_saved_array(
             HL_MONTY_EXT_CONSTANTS,
-            |arr| arr.map(|x| GoldilocksMonty::from_u64(x)),
+            |arr| arr.map(GoldilocksMonty::from_u64),
         ),
         HL_MONTY_INT_CONSTANTS
             .iter()
@@ -206,9 +226,7 @@ fn bench_poseidon2_monty_throughput(c: &mut Criterion) {
     );
 
     // Precompute all inputs
-    let inputs: Vec<[GoldilocksMonty; WIDTH]> = (0..ARRAY_SIZE)
-        .map(|_| array::from_fn(|_| rng.random::<GoldilocksMonty>()))
-        .collect();
+    let inputs = monty_inputs::<WIDTH>(&canonical_inputs(&mut rng, ARRAY_SIZE));
 
     c.bench_function("poseidon2_monty_throughput_10k", |b| {
         b.iter(|| {
@@ -230,7 +248,7 @@ fn bench_poseidon2_tree_hashing_std(c: &mut Criterion) {
     let poseidon2: Poseidon2GoldilocksHLStd<WIDTH> = Poseidon2::new(
         ExternalLayerConstants::<GoldilocksStd, WIDTH>::new_from_saved_array(
             HL_STD_EXT_CONSTANTS,
-            |arr| arr.map(|x| GoldilocksStd::from_u64(x)),
+            |arr| arr.map(GoldilocksStd::from_u64),
         ),
         HL_STD_INT_CONSTANTS
             .iter()
@@ -239,9 +257,7 @@ fn bench_poseidon2_tree_hashing_std(c: &mut Criterion) {
     );
 
     // Precompute all leaf data
-    let leaves: Vec<[GoldilocksStd; 4]> = (0..LEAF_COUNT)
-        .map(|_| array::from_fn(|_| rng.random::<GoldilocksStd>()))
-        .collect();
+    let leaves = std_inputs::<4>(&canonical_inputs(&mut rng, LEAF_COUNT));
 
     c.bench_function("poseidon2_std_tree_hash_1024", |b| {
         b.iter(|| {
@@ -282,7 +298,7 @@ fn bench_poseidon2_tree_hashing_monty(c: &mut Criterion) {
     let poseidon2: Poseidon2GoldilocksHLMonty<WIDTH> = Poseidon2::new(
         ExternalLayerConstants::<GoldilocksMonty, WIDTH>::new_from_saved_array(
             HL_MONTY_EXT_CONSTANTS,
-            |arr| arr.map(|x| GoldilocksMonty::from_u64(x)),
+            |arr| arr.map(GoldilocksMonty::from_u64),
         ),
         HL_MONTY_INT_CONSTANTS
             .iter()
@@ -291,9 +307,7 @@ fn bench_poseidon2_tree_hashing_monty(c: &mut Criterion) {
     );
 
     // Precompute all leaf data
-    let leaves: Vec<[GoldilocksMonty; 4]> = (0..LEAF_COUNT)
-        .map(|_| array::from_fn(|_| rng.random::<GoldilocksMonty>()))
-        .collect();
+    let leaves = monty_inputs::<4>(&canonical_inputs(&mut rng, LEAF_COUNT));
 
     c.bench_function("poseidon2_monty_tree_hash_1024", |b| {
         b.iter(|| {
@@ -113,6 +113,11 @@ impl Debug for Goldilocks {
     }
 }
 
+/// Samples the Montgomery representation directly.
+///
+/// A uniform value below `P` is used as the internal form as-is, so sampling does no
+/// Montgomery conversion and the result is still uniform over the field. The
+/// canonical value of a sampled element differs from the `u64` drawn from the RNG.
 impl Distribution<Goldilocks> for StandardUniform {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Goldilocks {
@@ -508,7 +513,7 @@ mod tests {
     #[test]
     fn test_basic_arithmetic() {
         let a_u64 = (2u128.pow(64) - 2u128.pow(32)) as u64;
-        let b_u64 = (a_u64 - 1) as u64;
+        let b_u64 = a_u64 - 1;
         let a = Goldilocks::new(a_u64);
         let b = Goldilocks::new(b_u64);
 
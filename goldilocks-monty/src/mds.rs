@@ -24,10 +24,11 @@ pub struct SmallConvolveGoldilocksMonty;
 
 impl Convolve<Goldilocks, i128, i64, i128> for SmallConvolveGoldilocksMonty {
     /// Return the lift of a Goldilocks element from Montgomery form.
-    /// We convert from Montgomery form to standard form for arithmetic operations.
+    /// We convert from Montgomery form to standard form for arithmetic operations,
+    /// matching `reduce`, which expects a standard-form result.
     #[inline(always)]
     fn read(input: Goldilocks) -> i128 {
-        input.0.value as i128
+        input.as_canonical_u64() as i128
     }
 
     /// Perform dot product with widened types to avoid overflow.
@@ -305,3 +306,33 @@ pub fn apply_circulant_fft_field<F: TwoAdicField, const N: usize, FFT: TwoAdicSu
     let output = fft.idft(product);
     output.try_into().unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use p3_symmetric::Permutation;
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    /// The width-32 path (u64 entries, standard-form round trip) must agree with running
+    /// the same matrix through the field-element FFT path used at width 64.
+    #[test]
+    fn test_width_32_matches_field_element_path() {
+        let column =
+            first_row_to_first_col(&MATRIX_CIRC_MDS_32_GOLDILOCKS_MONTY.map(Goldilocks::new));
+        let mut rng = SmallRng::seed_from_u64(32);
+
+        for _ in 0..16 {
+            let input: [Goldilocks; 32] = rng.random();
+            let field_path = apply_circulant_fft_field(FFT_ALGO, column, &input);
+            assert_eq!(MdsMatrixGoldilocksMonty.permute(input), field_path);
+        }
+
+        let edge = [Goldilocks::NEG_ONE; 32];
+        assert_eq!(
+            MdsMatrixGoldilocksMonty.permute(edge),
+            apply_circulant_fft_field(FFT_ALGO, column, &edge)
+        );
+    }
+}
@@ -1,5 +1,7 @@
 //! Tests for equivalence between standard and Montgomery implementations
 
+use alloc::vec::Vec;
+
 use p3_field::PrimeField64;
 use p3_poseidon2::{ExternalLayerConstants, Poseidon2};
 use p3_symmetric::Permutation;
@@ -14,7 +16,7 @@ fn test_poseidon2_equivalence_with_standard_goldilocks() {
     let test_input = [0u64, 1, 2, 3, 4, 5, 6, 7];
 
     // Montgomery version
-    let mut input_monty = test_input.map(|x| Goldilocks::new(x));
+    let mut input_monty = test_input.map(Goldilocks::new);
     let poseidon2_monty: crate::poseidon2::Poseidon2GoldilocksHL<8> = Poseidon2::new(
         ExternalLayerConstants::<Goldilocks, 8>::new_from_saved_array(
             crate::poseidon2::HL_GOLDILOCKS_MONTY_8_EXTERNAL_ROUND_CONSTANTS,
@@ -45,12 +47,63 @@ fn test_poseidon2_equivalence_with_standard_goldilocks() {
     );
 }
 
+/// Inputs converted to Montgomery form up front (as the Poseidon2 benchmarks do)
+/// permute to the same values as the standard Goldilocks implementation.
+#[test]
+fn test_preconverted_inputs_match_standard_poseidon2() {
+    use p3_field::PrimeCharacteristicRing;
+    use rand::rngs::SmallRng;
+    use rand::{RngCore, SeedableRng};
+
+    let poseidon2_monty: crate::poseidon2::Poseidon2GoldilocksHL<8> = Poseidon2::new(
+        ExternalLayerConstants::<Goldilocks, 8>::new_from_saved_array(
+            crate::poseidon2::HL_GOLDILOCKS_MONTY_8_EXTERNAL_ROUND_CONSTANTS,
+            Goldilocks::new_array,
+        ),
+        Goldilocks::new_array(crate::poseidon2::HL_GOLDILOCKS_MONTY_8_INTERNAL_ROUND_CONSTANTS)
+            .to_vec(),
+    );
+    let poseidon2_std: p3_goldilocks::Poseidon2GoldilocksHL<8> = Poseidon2::new(
+        ExternalLayerConstants::<p3_goldilocks::Goldilocks, 8>::new_from_saved_array(
+            p3_goldilocks::HL_GOLDILOCKS_8_EXTERNAL_ROUND_CONSTANTS,
+            |arr| arr.map(p3_goldilocks::Goldilocks::from_u64),
+        ),
+        p3_goldilocks::HL_GOLDILOCKS_8_INTERNAL_ROUND_CONSTANTS
+            .iter()
+            .map(|&x| p3_goldilocks::Goldilocks::from_u64(x))
+            .collect(),
+    );
+
+    let mut rng = SmallRng::seed_from_u64(42);
+    let raw: Vec<[u64; 8]> = (0..16)
+        .map(|_| core::array::from_fn(|_| rng.next_u64() % crate::GOLDILOCKS_PRIME))
+        .collect();
+    let preconverted: Vec<[Goldilocks; 8]> =
+        raw.iter().map(|&r| Goldilocks::new_array(r)).collect();
+
+    for (input, raw) in preconverted.iter().zip(&raw) {
+        let mut monty = *input;
+        poseidon2_monty.permute_mut(&mut monty);
+
+        let mut converted_in_loop = raw.map(Goldilocks::from_u64);
+        poseidon2_monty.permute_mut(&mut converted_in_loop);
+        assert_eq!(monty, converted_in_loop);
+
+        let mut std = raw.map(p3_goldilocks::Goldilocks::from_u64);
+        poseidon2_std.permute_mut(&mut std);
+        assert_eq!(
+            monty.map(|x| x.as_canonical_u64()),
+            std.map(|x| x.as_canonical_u64())
+        );
+    }
+}
+
 /// Test MDS matrix with known inputs
 #[test]
 fn test_mds_matrix_functionality() {
     use crate::mds::MdsMatrixGoldilocksMonty;
 
-    let mds = MdsMatrixGoldilocksMonty::default();
+    let mds = MdsMatrixGoldilocksMonty;
 
     // Test with 8 elements
     let input_8 = [
@@ -0,0 +1,51 @@
+//! Smoke test for committing a matrix with Poseidon2 `MerkleTreeMmcs` over the
+//! Montgomery field, the setup timed by the `merkle_commit` bench.
+
+use p3_commit::Mmcs;
+use p3_field::PrimeCharacteristicRing;
+use p3_goldilocks_monty::{Goldilocks, Poseidon2Goldilocks};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Dimensions;
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_symmetric::{PaddingFreeSponge, TruncatedPermutation};
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+type Perm = Poseidon2Goldilocks<16>;
+type PoseidonMmcs = MerkleTreeMmcs<
+    Goldilocks,
+    Goldilocks,
+    PaddingFreeSponge<Perm, 16, 8, 4>,
+    TruncatedPermutation<Perm, 2, 4, 16>,
+    4,
+>;
+
+#[test]
+fn commit_and_open_small_matrix() {
+    const ROWS: usize = 16;
+    const WIDTH: usize = 80;
+
+    let perm = Perm::new_from_rng_128(&mut SmallRng::seed_from_u64(42));
+    let mmcs = PoseidonMmcs::new(
+        PaddingFreeSponge::new(perm.clone()),
+        TruncatedPermutation::new(perm),
+    );
+    let values = (0..ROWS * WIDTH)
+        .map(|i| Goldilocks::from_u64(i as u64))
+        .collect();
+    let matrix = RowMajorMatrix::new(values, WIDTH);
+
+    let (commitment, prover_data) = mmcs.commit_matrix(matrix.clone());
+    let opening = mmcs.open_batch(5, &prover_data);
+    assert_eq!(
+        opening.opened_values[0],
+        matrix.values[5 * WIDTH..6 * WIDTH]
+    );
+
+    let dims = [Dimensions {
+        width: WIDTH,
+        height: ROWS,
+    }];
+    mmcs.verify_batch(&commitment, &dims, 5, (&opening).into())
+        .expect("Opening should verify against the commitment");
+}
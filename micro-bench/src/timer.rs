@@ -0,0 +1,65 @@
+//! Millisecond timing for the wasm benchmark entry points
+
+/// A clock reading milliseconds, abstracted so timing can be exercised natively
+pub trait WasmTimer {
+    /// Current time in milliseconds
+    fn now_ms(&mut self) -> f64;
+}
+
+/// The browser clock, `Date.now()`; only available when running under JS
+pub struct JsDateTimer;
+
+impl WasmTimer for JsDateTimer {
+    fn now_ms(&mut self) -> f64 {
+        js_sys::Date::now()
+    }
+}
+
+/// Run `f` and return its result with the elapsed time in milliseconds on `timer`
+pub fn time_with<T: WasmTimer, F: FnOnce() -> R, R>(timer: &mut T, f: F) -> (R, f64) {
+    let start = timer.now_ms();
+    let result = f();
+    let end = timer.now_ms();
+    (result, end - start)
+}
+
+/// Run `f` and return its result with the elapsed time in milliseconds on the JS clock
+pub fn time_js<F: FnOnce() -> R, R>(f: F) -> (R, f64) {
+    time_with(&mut JsDateTimer, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Advances by a fixed step on every reading
+    struct StepTimer {
+        now: f64,
+        step: f64,
+    }
+
+    impl WasmTimer for StepTimer {
+        fn now_ms(&mut self) -> f64 {
+            self.now += self.step;
+            self.now
+        }
+    }
+
+    #[test]
+    fn test_time_with_returns_result_and_elapsed() {
+        let mut timer = StepTimer {
+            now: 1000.0,
+            step: 12.5,
+        };
+        let mut ran = false;
+
+        let (result, elapsed) = time_with(&mut timer, || {
+            ran = true;
+            42
+        });
+
+        assert!(ran);
+        assert_eq!(result, 42);
+        assert_eq!(elapsed, 12.5);
+    }
+}
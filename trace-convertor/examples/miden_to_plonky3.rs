@@ -41,7 +41,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("   📝 Assembling Miden program...");
     let program = Assembler::default()
         .assemble_program(masm_code)
-        .expect("Failed to compile Miden Assembly code");
+        .map_err(|e| format!("Failed to compile Miden Assembly code: {e}"))?;
 
     println!("   ▶️  Executing Miden program...");
     let stack_inputs = StackInputs::default();
@@ -49,8 +49,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut host = DefaultHost::default();
     let options = ExecutionOptions::default();
 
-    let miden_trace = execute(&program, stack_inputs, advice_inputs, &mut host, options)
-        .expect("Failed to execute Miden program");
+    let miden_trace = execute(&program, stack_inputs, advice_inputs, &mut host, options)?;
 
     println!("   ✅ Miden execution completed");
     println!(
@@ -134,7 +133,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n🔄 Step 5: Converting complete execution (trace + constraints)...");
     let complete_conversion_start = std::time::Instant::now();
 
-    let (plonky3_trace_complete, miden_air) = convert_miden_execution::<Goldilocks>(&miden_trace)?;
+    let (plonky3_trace_complete, miden_air, public_values) =
+        convert_miden_execution::<Goldilocks>(&miden_trace)?;
 
     let complete_conversion_time = complete_conversion_start.elapsed();
     println!(
@@ -158,19 +158,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         "      Matches trace: {}",
         BaseAir::<Goldilocks>::width(&miden_air) == plonky3_trace.width()
     );
+    println!(
+        "      Public values: {} (stack inputs and outputs)",
+        public_values.to_vec::<Goldilocks>().len()
+    );
 
     // === Step 6: Integration Ready ===
     println!("\n🔐 Step 6: Ready for Plonky3 proving!");
     println!("   Both the trace and AIR are now ready:");
     println!("   ```rust");
     println!("   // The complete conversion provides everything needed");
-    println!("   let (trace, air) = convert_miden_execution::<Goldilocks>(&miden_trace)?;");
+    println!("   let (trace, air, public_values) = convert_miden_execution::<Goldilocks>(&miden_trace)?;");
     println!("   ");
     println!("   // Set up Plonky3 configuration");
     println!("   let config = create_plonky3_config();");
     println!("   ");
     println!("   // Generate the proof!");
-    println!("   let proof = prove(&config, &air, trace, &public_values);");
+    println!("   let proof = prove(&config, &air, trace, &public_values.to_vec());");
     println!("   ```");
 
     println!("\n🎉 Example completed successfully!");
@@ -53,7 +53,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // === Step 2: Convert to Plonky3 ===
     println!("\n🔄 Step 2: Converting to Plonky3 format...");
 
-    let (plonky3_trace, miden_air) =
+    let (plonky3_trace, miden_air, public_values) =
         convert_miden_execution::<p3_goldilocks::Goldilocks>(&miden_trace)?;
 
     println!("   ✅ Conversion successful");
@@ -68,6 +68,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         "   🏗️  AIR width: {}",
         BaseAir::<p3_goldilocks::Goldilocks>::width(&miden_air)
     );
+    println!(
+        "   📤 Stack outputs: {:?}",
+        &public_values.stack_outputs[..4]
+    );
 
     // === Step 3: Constraint System Validation ===
     println!("\n🔍 Step 3: Validating constraint system structure...");
@@ -105,6 +109,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n🚀 Ready for full proof generation!");
     println!("   The constraint system is validated and ready to use with:");
     println!("   ```rust");
+    println!("   let public_values = public_values.to_vec();");
     println!("   let proof = prove(&config, &miden_air, plonky3_trace, &public_values);");
     println!("   let result = verify(&config, &miden_air, &proof, &public_values);");
     println!("   ```");
@@ -162,7 +167,7 @@ mod tests {
             )
             .expect("Program should execute");
 
-            let (_plonky3_trace, _air) =
+            let (_plonky3_trace, _air, _public_values) =
                 convert_miden_execution::<p3_goldilocks::Goldilocks>(&trace)
                     .expect("Conversion should succeed");
 
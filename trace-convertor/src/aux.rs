@@ -0,0 +1,100 @@
+//! Conversion of Miden's auxiliary (running product / bus) columns.
+
+use alloc::vec::Vec;
+
+use miden_core::{Felt, FieldElement};
+use miden_processor::ExecutionTrace;
+use p3_field::ExtensionField;
+use p3_matrix::dense::RowMajorMatrix;
+use winter_prover::Trace;
+
+use crate::{check_column_lengths, ConversionError, MidenCompatibleField, TraceConverter};
+
+impl TraceConverter {
+    /// Build Miden's auxiliary trace and convert it to a Plonky3 RowMajorMatrix
+    ///
+    /// The columns are built by Miden from `rand_elements` (Miden extension elements, e.g.
+    /// `QuadFelt`, as many as the trace info asks for) and each element is copied
+    /// coefficient by coefficient into `E`, so `ME::EXTENSION_DEGREE` must equal
+    /// `E::DIMENSION`. Coefficients are kept in Miden's basis: Miden and Plonky3 may
+    /// reduce by different irreducible polynomials, so products only agree with Miden's
+    /// when `E` uses the same one. Rows are padded with zeros to the height of
+    /// [`Self::convert`].
+    pub fn convert_aux<F, E, ME>(
+        miden_trace: &ExecutionTrace,
+        rand_elements: &[ME],
+    ) -> Result<RowMajorMatrix<E>, ConversionError>
+    where
+        F: MidenCompatibleField,
+        E: ExtensionField<F>,
+        ME: FieldElement<BaseField = Felt>,
+    {
+        if ME::EXTENSION_DEGREE != E::DIMENSION {
+            return Err(ConversionError::FieldConversion(alloc::format!(
+                "Miden extension of degree {} does not match target extension of degree {}",
+                ME::EXTENSION_DEGREE,
+                E::DIMENSION
+            )));
+        }
+
+        let aux_segment = miden_trace
+            .build_aux_trace(rand_elements)
+            .ok_or(ConversionError::EmptyTrace)?;
+        let columns: Vec<&[ME]> = (0..aux_segment.num_cols())
+            .map(|col_idx| aux_segment.get_column(col_idx))
+            .collect();
+        convert_ext_columns::<F, E, ME>(&columns, miden_trace.length())
+    }
+
+    /// Convert the main and auxiliary traces together
+    ///
+    /// Both matrices come from the same Miden trace and so share one padded height,
+    /// which is what a prover committing to them as two segments expects.
+    pub fn convert_with_aux<F, E, ME>(
+        miden_trace: &ExecutionTrace,
+        rand_elements: &[ME],
+    ) -> Result<(RowMajorMatrix<F>, RowMajorMatrix<E>), ConversionError>
+    where
+        F: MidenCompatibleField,
+        E: ExtensionField<F>,
+        ME: FieldElement<BaseField = Felt>,
+    {
+        let main = Self::convert(miden_trace)?;
+        let aux = Self::convert_aux::<F, E, ME>(miden_trace, rand_elements)?;
+        debug_assert_eq!(main.values.len() / main.width, aux.values.len() / aux.width);
+        Ok((main, aux))
+    }
+}
+
+/// Column-major Miden extension elements to a zero-padded row-major matrix over `E`.
+fn convert_ext_columns<F, E, ME>(
+    columns: &[&[ME]],
+    height: usize,
+) -> Result<RowMajorMatrix<E>, ConversionError>
+where
+    F: MidenCompatibleField,
+    E: ExtensionField<F>,
+    ME: FieldElement<BaseField = Felt>,
+{
+    let width = columns.len();
+    if height == 0 || width == 0 {
+        return Err(ConversionError::EmptyTrace);
+    }
+    check_column_lengths(columns, height)?;
+
+    let padded_height = height.next_power_of_two();
+    let mut data = Vec::with_capacity(padded_height * width);
+    for row_idx in 0..padded_height {
+        if row_idx >= height {
+            data.extend(core::iter::repeat_n(E::ZERO, width));
+            continue;
+        }
+        for column in columns {
+            let coeffs = ME::slice_as_base_elements(core::slice::from_ref(&column[row_idx]));
+            data.push(E::from_basis_coefficients_fn(|i| {
+                F::from_miden_felt(coeffs[i])
+            }));
+        }
+    }
+    Ok(RowMajorMatrix::new(data, width))
+}
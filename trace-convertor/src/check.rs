@@ -0,0 +1,133 @@
+//! Row-by-row constraint checking ahead of proving.
+
+use alloc::string::String;
+use core::fmt;
+
+use p3_air::{Air, AirBuilder, AirBuilderWithPublicValues};
+use p3_field::PrimeField;
+use p3_matrix::dense::{RowMajorMatrix, RowMajorMatrixView};
+use p3_matrix::stack::VerticalPair;
+use p3_matrix::Matrix;
+
+use crate::{MidenProcessorAir, TraceConverter};
+
+/// The first constraint a trace fails, from [`TraceConverter::check_constraints`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintViolation {
+    /// Row the constraint was evaluated on (its next row is `row + 1`, wrapping)
+    pub row: usize,
+    /// Which constraint failed, as listed by [`MidenProcessorAir::describe_constraints`]
+    pub message: String,
+}
+
+impl fmt::Display for ConstraintViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Constraint violated on row {}: {}",
+            self.row, self.message
+        )
+    }
+}
+
+impl core::error::Error for ConstraintViolation {}
+
+impl TraceConverter {
+    /// Evaluate `air` on every pair of adjacent rows and report the first failure
+    ///
+    /// Rows wrap around and the first/last/transition selectors are set as in Plonky3's
+    /// own debug check, but a violation is returned instead of panicking, so a bad trace
+    /// can be diagnosed before `prove` fails on it. Constraints are checked row by row,
+    /// in evaluation order within a row.
+    pub fn check_constraints<F: PrimeField>(
+        matrix: &RowMajorMatrix<F>,
+        air: &MidenProcessorAir,
+    ) -> Result<(), ConstraintViolation> {
+        let height = matrix.height();
+        for row in 0..height {
+            let local = matrix.row_slice(row).expect("row within trace");
+            let next = matrix
+                .row_slice((row + 1) % height)
+                .expect("row within trace");
+            let mut builder = RecordingBuilder {
+                main: VerticalPair::new(
+                    RowMajorMatrixView::new_row(&*local),
+                    RowMajorMatrixView::new_row(&*next),
+                ),
+                is_first_row: F::from_bool(row == 0),
+                is_last_row: F::from_bool(row == height - 1),
+                is_transition: F::from_bool(row != height - 1),
+                num_constraints: 0,
+                first_failure: None,
+            };
+            air.eval(&mut builder);
+
+            if let Some((index, value)) = builder.first_failure {
+                let description = air.describe_constraints().swap_remove(index);
+                return Err(ConstraintViolation {
+                    row,
+                    message: alloc::format!(
+                        "constraint {} ({}) evaluates to {}",
+                        index,
+                        description,
+                        value
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Evaluates constraints on concrete rows, remembering the first non-zero one
+struct RecordingBuilder<'a, F: PrimeField> {
+    main: VerticalPair<RowMajorMatrixView<'a, F>, RowMajorMatrixView<'a, F>>,
+    is_first_row: F,
+    is_last_row: F,
+    is_transition: F,
+    /// Constraints evaluated so far on this row
+    num_constraints: usize,
+    /// Index and value of the first non-zero constraint
+    first_failure: Option<(usize, F)>,
+}
+
+impl<'a, F: PrimeField> AirBuilder for RecordingBuilder<'a, F> {
+    type F = F;
+    type Expr = F;
+    type Var = F;
+    type M = VerticalPair<RowMajorMatrixView<'a, F>, RowMajorMatrixView<'a, F>>;
+
+    fn main(&self) -> Self::M {
+        self.main
+    }
+
+    fn is_first_row(&self) -> Self::Expr {
+        self.is_first_row
+    }
+
+    fn is_last_row(&self) -> Self::Expr {
+        self.is_last_row
+    }
+
+    fn is_transition_window(&self, size: usize) -> Self::Expr {
+        assert_eq!(size, 2, "only a window of two rows is supported");
+        self.is_transition
+    }
+
+    fn assert_zero<I: Into<Self::Expr>>(&mut self, x: I) {
+        let value = x.into();
+        if value != F::ZERO && self.first_failure.is_none() {
+            self.first_failure = Some((self.num_constraints, value));
+        }
+        self.num_constraints += 1;
+    }
+}
+
+/// Traces are checked without public values, so no stack boundary is asserted
+impl<F: PrimeField> AirBuilderWithPublicValues for RecordingBuilder<'_, F> {
+    type PublicVar = F;
+
+    fn public_values(&self) -> &[F] {
+        &[]
+    }
+}
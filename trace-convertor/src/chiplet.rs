@@ -0,0 +1,111 @@
+//! Chiplet-only conversion and AIR, for developing chiplet constraints in isolation.
+
+use alloc::vec::Vec;
+
+use miden_processor::ExecutionTrace;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::PrimeCharacteristicRing;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+use winter_prover::Trace;
+
+use crate::layout::{CHIPLETS_OFFSET, CHIPLETS_WIDTH};
+use crate::{padded_cell, ConversionError, MidenCompatibleField, TraceConverter};
+
+/// A Plonky3 AIR over the chiplet columns alone
+///
+/// Enforces exactly the chiplet constraints of
+/// [`MidenProcessorAir`](crate::MidenProcessorAir), on a trace produced by
+/// [`TraceConverter::convert_chiplets_only`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ChipletAir;
+
+impl<F> BaseAir<F> for ChipletAir {
+    fn width(&self) -> usize {
+        CHIPLETS_WIDTH
+    }
+}
+
+impl<AB: AirBuilder> Air<AB> for ChipletAir {
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (current, next) = (
+            main.row_slice(0)
+                .expect("Matrix must have at least one row"),
+            main.row_slice(1)
+                .expect("Matrix must have at least two rows for transitions"),
+        );
+
+        enforce_chiplet_constraints(builder, &current, &next);
+    }
+}
+
+impl TraceConverter {
+    /// Convert only the chiplet region of a Miden execution trace
+    ///
+    /// The result holds the [`CHIPLETS_WIDTH`] chiplet columns in Miden order, padded
+    /// with zero rows like [`Self::convert`]. Prove it with [`ChipletAir`].
+    pub fn convert_chiplets_only<F: MidenCompatibleField>(
+        miden_trace: &ExecutionTrace,
+    ) -> Result<RowMajorMatrix<F>, ConversionError> {
+        let columns = Self::main_columns(miden_trace)?;
+        let height = miden_trace.length();
+
+        if columns.len() < CHIPLETS_OFFSET + CHIPLETS_WIDTH {
+            return Err(ConversionError::InvalidDimensions {
+                rows: height,
+                cols: columns.len(),
+            });
+        }
+
+        let padded_height = height.next_power_of_two();
+        let mut data = Vec::with_capacity(padded_height * CHIPLETS_WIDTH);
+        for row_idx in 0..padded_height {
+            for column in columns.iter().skip(CHIPLETS_OFFSET).take(CHIPLETS_WIDTH) {
+                let value = padded_cell(column, row_idx, height);
+                data.push(F::from_miden_felt(value));
+            }
+        }
+
+        Ok(RowMajorMatrix::new(data, CHIPLETS_WIDTH))
+    }
+}
+
+/// Chiplet constraints (hasher, bitwise operations, memory) over chiplet-local rows
+///
+/// `current` and `next` start at the first chiplet column.
+pub(crate) fn enforce_chiplet_constraints<AB: AirBuilder>(
+    builder: &mut AB,
+    current: &[AB::Var],
+    next: &[AB::Var],
+) {
+    // Chiplet selector constraints - first few columns are selectors
+    for &selector in &current[..6] {
+        // Selectors should be binary
+        builder.assert_bool(selector);
+    }
+
+    // Hash chiplet constraints (when selector[0] = 0)
+    let hash_selector = current[0];
+    let _is_hash_op = AB::Expr::ONE - hash_selector; // 1 when hash_selector = 0
+
+    // Memory chiplet constraints (when selector pattern = [1,1,0,...])
+    let is_memory_op = current[0] * current[1] * (AB::Expr::ONE - current[2]);
+
+    // When this is a memory operation, enforce memory constraints on the next row
+    builder.when_transition().when(is_memory_op).assert_zero(
+        // Simplified memory consistency constraint
+        // Real implementation: memory values should be consistent with context/address
+        next[10] - current[10],
+    );
+
+    // Bitwise chiplet constraints (when selector pattern = [1,0,...])
+    let is_bitwise_op = current[0] * (AB::Expr::ONE - current[1]);
+
+    // When this is a bitwise operation, enforce bitwise constraints
+    builder.when(is_bitwise_op).assert_zero(
+        // Simplified bitwise constraint
+        // Real implementation: a OP b = output with proper bit decomposition
+        current[15] - AB::F::ZERO,
+    );
+}
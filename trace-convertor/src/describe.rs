@@ -0,0 +1,104 @@
+//! Rendering of symbolic AIR constraints as readable strings.
+
+use alloc::format;
+use alloc::string::String;
+
+use p3_field::PrimeField64;
+use p3_uni_stark::{Entry, SymbolicExpression};
+
+/// Binding strength of a rendered expression, used to decide where parentheses go
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+enum Precedence {
+    Sum,
+    Product,
+    Atom,
+}
+
+/// Describe a constraint `expr = 0`, naming main-trace columns with `column_name`
+///
+/// Row selectors wrapped around the whole constraint become a prefix, e.g.
+/// `transition: clk' - (clk + 1) = 0`; next-row columns are primed.
+pub(crate) fn describe_constraint<F: PrimeField64>(
+    expr: &SymbolicExpression<F>,
+    column_name: &impl Fn(usize) -> String,
+) -> String {
+    if let SymbolicExpression::Mul { x, y, .. } = expr {
+        let selector = match **x {
+            SymbolicExpression::IsFirstRow => Some("first row"),
+            SymbolicExpression::IsLastRow => Some("last row"),
+            SymbolicExpression::IsTransition => Some("transition"),
+            _ => None,
+        };
+        if let Some(selector) = selector {
+            return format!("{selector}: {} = 0", render(y, column_name).0);
+        }
+    }
+    format!("{} = 0", render(expr, column_name).0)
+}
+
+fn render<F: PrimeField64>(
+    expr: &SymbolicExpression<F>,
+    column_name: &impl Fn(usize) -> String,
+) -> (String, Precedence) {
+    let operand = |e: &SymbolicExpression<F>, min: Precedence| {
+        let (s, prec) = render(e, column_name);
+        if prec < min {
+            format!("({s})")
+        } else {
+            s
+        }
+    };
+
+    match expr {
+        SymbolicExpression::Variable(var) => {
+            let name = match var.entry {
+                Entry::Main { offset: 0 } => column_name(var.index),
+                Entry::Main { offset } => {
+                    format!("{}{}", column_name(var.index), "'".repeat(offset))
+                }
+                _ => format!("{:?}[{}]", var.entry, var.index),
+            };
+            (name, Precedence::Atom)
+        }
+        SymbolicExpression::IsFirstRow => ("is_first_row".into(), Precedence::Atom),
+        SymbolicExpression::IsLastRow => ("is_last_row".into(), Precedence::Atom),
+        SymbolicExpression::IsTransition => ("is_transition".into(), Precedence::Atom),
+        SymbolicExpression::Constant(c) => {
+            let value = c.as_canonical_u64();
+            // Show field elements near the modulus as small negatives
+            if value > F::ORDER_U64 / 2 {
+                (format!("-{}", F::ORDER_U64 - value), Precedence::Sum)
+            } else {
+                (format!("{value}"), Precedence::Atom)
+            }
+        }
+        SymbolicExpression::Add { x, y, .. } => (
+            format!(
+                "{} + {}",
+                operand(x, Precedence::Sum),
+                operand(y, Precedence::Product)
+            ),
+            Precedence::Sum,
+        ),
+        SymbolicExpression::Sub { x, y, .. } => (
+            format!(
+                "{} - {}",
+                operand(x, Precedence::Sum),
+                operand(y, Precedence::Product)
+            ),
+            Precedence::Sum,
+        ),
+        SymbolicExpression::Mul { x, y, .. } => (
+            format!(
+                "{} * {}",
+                operand(x, Precedence::Product),
+                operand(y, Precedence::Atom)
+            ),
+            Precedence::Product,
+        ),
+        SymbolicExpression::Neg { x, .. } => (
+            format!("-{}", operand(x, Precedence::Atom)),
+            Precedence::Sum,
+        ),
+    }
+}
@@ -0,0 +1,94 @@
+//! Fields that Miden trace values can be converted into.
+
+use miden_core::Felt;
+use p3_field::integers::QuotientMap;
+use p3_field::PrimeField64;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for p3_goldilocks::Goldilocks {}
+    impl Sealed for p3_goldilocks_monty::Goldilocks {}
+}
+
+/// Construction of field elements from Miden `Felt`s
+///
+/// The default goes through `from_u64`, which reduces the value again; the Goldilocks
+/// fields override it to take `Felt`'s already canonical value as is. Sealed along
+/// with [`MidenCompatibleField`].
+pub trait FromMidenFelt: PrimeField64 + sealed::Sealed {
+    /// The element with the same canonical value as `felt`
+    #[inline]
+    fn from_miden_felt(felt: Felt) -> Self {
+        Self::from_u64(felt.as_int())
+    }
+}
+
+impl FromMidenFelt for p3_goldilocks::Goldilocks {
+    #[inline]
+    fn from_miden_felt(felt: Felt) -> Self {
+        // SAFETY: any u64 is a valid internal Goldilocks value, canonical or not
+        unsafe { Self::from_canonical_unchecked(felt.as_int()) }
+    }
+}
+
+impl FromMidenFelt for p3_goldilocks_monty::Goldilocks {
+    #[inline]
+    fn from_miden_felt(felt: Felt) -> Self {
+        // `as_int` is below the shared modulus, so the `% P` of `from_u64` is redundant
+        Self::new(felt.as_int())
+    }
+}
+
+/// A Plonky3 field over the same prime as Miden's `Felt`
+///
+/// Conversion maps each canonical `Felt` value straight to the same value in `F`
+/// (see [`FromMidenFelt`]), which is only meaningful when `F` is also the Goldilocks field. This trait is sealed, so a field
+/// with any other modulus fails to compile instead of silently reducing values mod a
+/// different prime. Use [`TraceConverter::convert_unchecked`](crate::TraceConverter::convert_unchecked)
+/// to experiment with other fields anyway.
+pub trait MidenCompatibleField: FromMidenFelt {}
+
+impl MidenCompatibleField for p3_goldilocks::Goldilocks {}
+impl MidenCompatibleField for p3_goldilocks_monty::Goldilocks {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use miden_core::{FieldElement, StarkField};
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    fn assert_miden_modulus<F: MidenCompatibleField>() {
+        assert_eq!(F::ORDER_U64, Felt::MODULUS);
+    }
+
+    #[test]
+    fn test_compatible_fields_share_miden_modulus() {
+        assert_miden_modulus::<p3_goldilocks::Goldilocks>();
+        assert_miden_modulus::<p3_goldilocks_monty::Goldilocks>();
+    }
+
+    fn assert_matches_from_u64<F: MidenCompatibleField>(felts: &[Felt]) {
+        for &felt in felts {
+            assert_eq!(
+                F::from_miden_felt(felt),
+                F::from_u64(felt.as_int()),
+                "{felt}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_miden_felt_matches_from_u64() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let mut felts: Vec<Felt> = (0..10_000).map(|_| Felt::new(rng.random())).collect();
+        felts.extend([Felt::ZERO, Felt::ONE, Felt::new(Felt::MODULUS - 1)]);
+
+        assert_matches_from_u64::<p3_goldilocks::Goldilocks>(&felts);
+        assert_matches_from_u64::<p3_goldilocks_monty::Goldilocks>(&felts);
+    }
+}
@@ -0,0 +1,189 @@
+//! Column indices of Miden's main trace.
+//!
+//! Taken from `miden-air` rather than hardcoded, so they follow the pinned Miden version.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::ops::Range;
+
+use miden_air::trace::{
+    decoder, range, stack, CHIPLETS_RANGE, CHIPLETS_WIDTH as MIDEN_CHIPLETS_WIDTH,
+    DECODER_TRACE_OFFSET, DECODER_TRACE_RANGE, DECODER_TRACE_WIDTH, FN_HASH_RANGE,
+    PADDED_TRACE_WIDTH, RANGE_CHECK_TRACE_OFFSET, RANGE_CHECK_TRACE_WIDTH, STACK_TRACE_OFFSET,
+    STACK_TRACE_RANGE, STACK_TRACE_WIDTH, SYS_TRACE_OFFSET, SYS_TRACE_WIDTH,
+};
+
+/// First decoder column in the main trace
+pub const DECODER_OFFSET: usize = DECODER_TRACE_OFFSET;
+
+/// Number of decoder columns
+pub const DECODER_WIDTH: usize = DECODER_TRACE_WIDTH;
+
+/// Columns of the top 16 operand stack elements, top first
+pub const STACK_TOP_RANGE: Range<usize> = STACK_TRACE_OFFSET + stack::STACK_TOP_RANGE.start
+    ..STACK_TRACE_OFFSET + stack::STACK_TOP_RANGE.end;
+
+/// First chiplet column in the main trace
+pub const CHIPLETS_OFFSET: usize = CHIPLETS_RANGE.start;
+
+/// Number of chiplet columns
+pub const CHIPLETS_WIDTH: usize = MIDEN_CHIPLETS_WIDTH;
+
+/// Control-flow flag: the block being ended is the body of a loop
+pub const IS_LOOP_BODY_FLAG_COL: usize = DECODER_OFFSET + decoder::IS_LOOP_BODY_FLAG_COL_IDX;
+
+/// Control-flow flag: the block being ended is a loop
+pub const IS_LOOP_FLAG_COL: usize = DECODER_OFFSET + decoder::IS_LOOP_FLAG_COL_IDX;
+
+/// Control-flow flag: the block being ended is a call
+pub const IS_CALL_FLAG_COL: usize = DECODER_OFFSET + decoder::IS_CALL_FLAG_COL_IDX;
+
+/// Control-flow flag: the block being ended is a syscall
+pub const IS_SYSCALL_FLAG_COL: usize = DECODER_OFFSET + decoder::IS_SYSCALL_FLAG_COL_IDX;
+
+/// Decoder flag set while executing operations inside a basic block (span)
+pub const IN_SPAN_COL: usize = DECODER_OFFSET + decoder::IN_SPAN_COL_IDX;
+
+/// Number of operation groups left to decode in the current basic block
+pub const GROUP_COUNT_COL: usize = DECODER_OFFSET + decoder::GROUP_COUNT_COL_IDX;
+
+/// Range-check value column, relative to the start of the range region
+pub const RANGE_V_COL_IDX: usize = range::V_COL_IDX - RANGE_CHECK_TRACE_OFFSET;
+
+/// First of the range-check limb columns the converter appends after Miden's columns
+///
+/// Miden proves its range checks with a bus over auxiliary columns instead, so the
+/// converter splits each real row's range value `v` into a low and a high byte here,
+/// followed by the bits of both bytes. The columns are zero on padding rows.
+pub const RANGE_LIMBS_OFFSET: usize = PADDED_TRACE_WIDTH;
+
+/// Bits of the two limbs, low limb first and least significant bit first
+pub const RANGE_LIMB_BITS_OFFSET: usize = RANGE_LIMBS_OFFSET + 2;
+
+/// Number of range-check limb columns: the two limbs and their 16 bits
+pub const RANGE_LIMBS_WIDTH: usize = 2 + 16;
+
+/// Selector the converter appends after the range limbs: 1 on padding rows, 0 on real ones
+pub const IS_PADDING_COL: usize = RANGE_LIMBS_OFFSET + RANGE_LIMBS_WIDTH;
+
+/// Number of columns the converter appends to Miden's: the range limbs and the selector
+pub const APPENDED_WIDTH: usize = RANGE_LIMBS_WIDTH + 1;
+
+/// Where each region of Miden's main trace starts and how wide it is
+///
+/// [`MidenProcessorAir`](crate::MidenProcessorAir) reads its columns through this rather
+/// than through the constants above, so a trace from a Miden version with a different
+/// layout can be checked by describing that layout. Columns inside a region keep
+/// Miden's relative order (e.g. `op_bits` right after the decoder's `addr` column).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MidenTraceLayout {
+    pub system_offset: usize,
+    pub system_width: usize,
+    pub decoder_offset: usize,
+    pub decoder_width: usize,
+    pub stack_offset: usize,
+    pub stack_width: usize,
+    pub range_offset: usize,
+    pub range_width: usize,
+    pub chiplets_offset: usize,
+    pub chiplets_width: usize,
+    /// First of the converter's range-check limb columns, past the last Miden column
+    pub range_limbs_offset: usize,
+    /// The converter's padding selector, after the range limbs
+    pub is_padding_col: usize,
+}
+
+impl MidenTraceLayout {
+    /// The layout of Miden 0.18, the version this crate is built against
+    pub const fn v0_18() -> Self {
+        Self {
+            system_offset: SYS_TRACE_OFFSET,
+            system_width: SYS_TRACE_WIDTH,
+            decoder_offset: DECODER_TRACE_OFFSET,
+            decoder_width: DECODER_TRACE_WIDTH,
+            stack_offset: STACK_TRACE_OFFSET,
+            stack_width: STACK_TRACE_WIDTH,
+            range_offset: RANGE_CHECK_TRACE_OFFSET,
+            range_width: RANGE_CHECK_TRACE_WIDTH,
+            chiplets_offset: CHIPLETS_RANGE.start,
+            chiplets_width: MIDEN_CHIPLETS_WIDTH,
+            range_limbs_offset: RANGE_LIMBS_OFFSET,
+            is_padding_col: IS_PADDING_COL,
+        }
+    }
+}
+
+impl Default for MidenTraceLayout {
+    fn default() -> Self {
+        Self::v0_18()
+    }
+}
+
+/// Short human-readable name of a main-trace column, e.g. `clk`, `op_bits[2]` or `s0`
+///
+/// Columns past Miden's trace width (the hash-rate padding) are named `col[i]`, and the
+/// converter's range limbs and selector after them `range_lo`, `range_hi`, `range_bits[i]`
+/// and `is_padding`.
+pub fn column_name(col: usize) -> String {
+    const SYSTEM_NAMES: [&str; 4] = ["clk", "fmp", "ctx", "in_syscall"];
+
+    if col < FN_HASH_RANGE.start {
+        return SYSTEM_NAMES[col - SYS_TRACE_OFFSET].to_string();
+    }
+    if FN_HASH_RANGE.contains(&col) {
+        return format!("fn_hash[{}]", col - FN_HASH_RANGE.start);
+    }
+    if DECODER_TRACE_RANGE.contains(&col) {
+        let idx = col - DECODER_OFFSET;
+        return match idx {
+            decoder::ADDR_COL_IDX => "addr".to_string(),
+            _ if decoder::OP_BITS_RANGE.contains(&idx) => {
+                format!("op_bits[{}]", idx - decoder::OP_BITS_OFFSET)
+            }
+            _ if decoder::HASHER_STATE_RANGE.contains(&idx) => {
+                format!("hasher[{}]", idx - decoder::HASHER_STATE_OFFSET)
+            }
+            decoder::IN_SPAN_COL_IDX => "in_span".to_string(),
+            decoder::GROUP_COUNT_COL_IDX => "group_count".to_string(),
+            decoder::OP_INDEX_COL_IDX => "op_index".to_string(),
+            _ if decoder::OP_BATCH_FLAGS_RANGE.contains(&idx) => {
+                format!("op_batch_flags[{}]", idx - decoder::OP_BATCH_FLAGS_OFFSET)
+            }
+            _ => format!(
+                "op_bits_extra[{}]",
+                idx - decoder::OP_BITS_EXTRA_COLS_OFFSET
+            ),
+        };
+    }
+    if STACK_TRACE_RANGE.contains(&col) {
+        let idx = col - STACK_TRACE_OFFSET;
+        return match idx {
+            stack::B0_COL_IDX => "b0".to_string(),
+            stack::B1_COL_IDX => "b1".to_string(),
+            stack::H0_COL_IDX => "h0".to_string(),
+            _ => format!("s{idx}"),
+        };
+    }
+    if col == RANGE_CHECK_TRACE_OFFSET {
+        return "range_m".to_string();
+    }
+    if col == RANGE_CHECK_TRACE_OFFSET + 1 {
+        return "range_v".to_string();
+    }
+    if CHIPLETS_RANGE.contains(&col) {
+        return format!("chiplets[{}]", col - CHIPLETS_RANGE.start);
+    }
+    if col == RANGE_LIMBS_OFFSET {
+        return "range_lo".to_string();
+    }
+    if col == RANGE_LIMBS_OFFSET + 1 {
+        return "range_hi".to_string();
+    }
+    if (RANGE_LIMB_BITS_OFFSET..IS_PADDING_COL).contains(&col) {
+        return format!("range_bits[{}]", col - RANGE_LIMB_BITS_OFFSET);
+    }
+    if col == IS_PADDING_COL {
+        return "is_padding".to_string();
+    }
+    format!("col[{col}]")
+}
@@ -0,0 +1,118 @@
+//! A `Matrix` view over Miden columns that converts `Felt` to `F` on access.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use miden_core::Felt;
+use miden_processor::ExecutionTrace;
+use p3_matrix::Matrix;
+use winter_prover::Trace;
+
+use crate::{
+    check_column_lengths, is_padding_cell, layout, padded_cell, range_limb_cells, ConversionError,
+    MidenCompatibleField, TraceConverter,
+};
+
+/// Lazily converted Plonky3 view of a Miden main trace.
+///
+/// Holds references to the Miden columns and converts each cell when it is read,
+/// so the full `Vec<F>` of [`TraceConverter::convert`](crate::TraceConverter::convert)
+/// is never materialized. Values, padding, range limbs and the padding selector match the eager
+/// conversion exactly; the cost is a conversion on every access.
+#[derive(Clone, Debug)]
+pub struct LazyMidenMatrix<'a, F> {
+    columns: Vec<&'a [Felt]>,
+    /// Number of real (unpadded) rows in the Miden trace
+    height: usize,
+    _phantom: PhantomData<F>,
+}
+
+impl<'a, F: MidenCompatibleField> LazyMidenMatrix<'a, F> {
+    /// Wrap raw Miden columns holding at least `height` values each
+    pub fn new(columns: Vec<&'a [Felt]>, height: usize) -> Result<Self, ConversionError> {
+        if height == 0 || columns.is_empty() {
+            return Err(ConversionError::EmptyTrace);
+        }
+        check_column_lengths(&columns, height)?;
+
+        Ok(Self {
+            columns,
+            height,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Wrap the main segment of a Miden execution trace
+    pub fn from_trace(miden_trace: &'a ExecutionTrace) -> Result<Self, ConversionError> {
+        let main_segment = miden_trace.main_segment();
+        let columns = (0..miden_trace.main_trace_width())
+            .map(|col_idx| main_segment.get_column(col_idx))
+            .collect();
+        Self::new(columns, miden_trace.length())
+    }
+
+    #[inline]
+    fn convert_cell(&self, r: usize, c: usize) -> F {
+        let width = self.columns.len();
+        if c < width {
+            F::from_miden_felt(padded_cell(self.columns[c], r, self.height))
+        } else if c < width + layout::RANGE_LIMBS_WIDTH {
+            range_limb_cells(&self.columns, r, self.height)
+                .nth(c - width)
+                .unwrap()
+        } else {
+            is_padding_cell(r, self.height)
+        }
+    }
+}
+
+impl<F: MidenCompatibleField> Matrix<F> for LazyMidenMatrix<'_, F> {
+    fn width(&self) -> usize {
+        self.columns.len() + layout::APPENDED_WIDTH
+    }
+
+    fn height(&self) -> usize {
+        self.height.next_power_of_two()
+    }
+
+    unsafe fn get_unchecked(&self, r: usize, c: usize) -> F {
+        self.convert_cell(r, c)
+    }
+
+    unsafe fn row_unchecked(
+        &self,
+        r: usize,
+    ) -> impl IntoIterator<Item = F, IntoIter = impl Iterator<Item = F> + Send + Sync> {
+        (0..self.width()).map(move |c| self.convert_cell(r, c))
+    }
+
+    unsafe fn row_subslice_unchecked(
+        &self,
+        r: usize,
+        start: usize,
+        end: usize,
+    ) -> impl core::ops::Deref<Target = [F]> {
+        (start..end)
+            .map(|c| self.convert_cell(r, c))
+            .collect::<Vec<_>>()
+    }
+}
+
+impl TraceConverter {
+    /// Convert a Miden execution trace one row at a time
+    ///
+    /// Yields the rows of [`Self::convert`] in order, padding included: every row past
+    /// the trace's length is zero apart from the padding selector. Only the row being
+    /// yielded is allocated, so the rows can be fed into a streaming commitment without
+    /// holding the Miden trace and the converted matrix side by side.
+    pub fn row_iter<'a, F: MidenCompatibleField + 'a>(
+        miden_trace: &'a ExecutionTrace,
+    ) -> Result<impl Iterator<Item = Vec<F>> + 'a, ConversionError> {
+        let matrix = LazyMidenMatrix::<F>::from_trace(miden_trace)?;
+        Ok((0..matrix.height()).map(move |r| {
+            (0..matrix.width())
+                .map(|c| matrix.convert_cell(r, c))
+                .collect()
+        }))
+    }
+}
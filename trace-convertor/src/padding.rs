@@ -0,0 +1,77 @@
+//! The padding selector column and the constraints that skip padding rows.
+
+use p3_air::AirBuilder;
+use p3_field::PrimeCharacteristicRing;
+
+use crate::MidenProcessorAir;
+
+impl MidenProcessorAir {
+    /// Enforce the shape of the `is_padding` selector
+    ///
+    /// It is boolean, zero on the first row, and once set stays set, so the padding
+    /// rows are exactly a suffix of the trace. How many rows are real is not bound
+    /// otherwise: a prover may start the padding early and skip the transitions after it.
+    pub(crate) fn enforce_padding_selector<AB: AirBuilder>(
+        &self,
+        builder: &mut AB,
+        current: &[AB::Var],
+        next: &[AB::Var],
+    ) {
+        let col = self.layout.is_padding_col;
+        let is_padding = current[col];
+        builder.assert_bool(is_padding);
+        builder.when_first_row().assert_zero(is_padding);
+        builder
+            .when_transition()
+            .when(is_padding)
+            .assert_one(next[col]);
+    }
+}
+
+/// An [`AirBuilder`] whose transitions only hold into real rows
+///
+/// `when_transition()` is additionally gated on the next row's `is_padding` being
+/// zero. Padding is a suffix, so a real next row implies a real current row and every
+/// transition between two real rows is still enforced, while the jump from the last
+/// real row into (zero) padding and the transitions between padding rows are not.
+/// Constraints on a single row are passed through unchanged.
+pub(crate) struct RealTransitionBuilder<'a, AB: AirBuilder> {
+    inner: &'a mut AB,
+    next_is_real: AB::Expr,
+}
+
+impl<'a, AB: AirBuilder> RealTransitionBuilder<'a, AB> {
+    pub(crate) fn new(inner: &'a mut AB, next_is_padding: AB::Var) -> Self {
+        Self {
+            inner,
+            next_is_real: AB::Expr::ONE - next_is_padding,
+        }
+    }
+}
+
+impl<AB: AirBuilder> AirBuilder for RealTransitionBuilder<'_, AB> {
+    type F = AB::F;
+    type Expr = AB::Expr;
+    type Var = AB::Var;
+    type M = AB::M;
+
+    fn main(&self) -> Self::M {
+        self.inner.main()
+    }
+
+    fn is_first_row(&self) -> Self::Expr {
+        self.inner.is_first_row()
+    }
+
+    fn is_last_row(&self) -> Self::Expr {
+        self.inner.is_last_row()
+    }
+
+    fn is_transition_window(&self, size: usize) -> Self::Expr {
+        self.inner.is_transition_window(size) * self.next_is_real.clone()
+    }
+
+    fn assert_zero<I: Into<Self::Expr>>(&mut self, x: I) {
+        self.inner.assert_zero(x);
+    }
+}
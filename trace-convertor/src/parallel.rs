@@ -0,0 +1,68 @@
+//! Multi-threaded conversion, behind the `parallel` feature.
+
+use alloc::vec;
+
+use miden_core::Felt;
+use miden_processor::ExecutionTrace;
+use p3_matrix::dense::RowMajorMatrix;
+use rayon::prelude::*;
+use winter_prover::Trace;
+
+use crate::{
+    check_column_lengths, is_padding_cell, layout, padded_cell, range_limb_cells, ConversionError,
+    MidenCompatibleField, TraceConverter,
+};
+
+/// Rows converted by one rayon task
+const ROWS_PER_CHUNK: usize = 1 << 10;
+
+impl TraceConverter {
+    /// Convert a Miden execution trace using all rayon threads
+    ///
+    /// Produces exactly the matrix of [`Self::convert`], padding included. The padded
+    /// height is split into chunks of rows, each written into its own slice of the
+    /// output buffer, so only traces of many thousands of rows gain from it.
+    pub fn convert_parallel<F: MidenCompatibleField>(
+        miden_trace: &ExecutionTrace,
+    ) -> Result<RowMajorMatrix<F>, ConversionError> {
+        let columns = Self::main_columns(miden_trace)?;
+        convert_columns_parallel(&columns, miden_trace.length())
+    }
+}
+
+/// [`convert_columns`](crate::convert_columns) over rayon row chunks.
+pub(crate) fn convert_columns_parallel<F: MidenCompatibleField>(
+    columns: &[&[Felt]],
+    height: usize,
+) -> Result<RowMajorMatrix<F>, ConversionError> {
+    let width = columns.len();
+    if height == 0 || width == 0 {
+        return Err(ConversionError::EmptyTrace);
+    }
+    check_column_lengths(columns, height)?;
+
+    let padded_height = height.next_power_of_two();
+    let out_width = width + layout::APPENDED_WIDTH;
+    let mut data = vec![F::ZERO; padded_height * out_width];
+    data.par_chunks_mut(ROWS_PER_CHUNK * out_width)
+        .enumerate()
+        .for_each(|(chunk_idx, chunk)| {
+            for (i, row) in chunk.chunks_exact_mut(out_width).enumerate() {
+                let row_idx = chunk_idx * ROWS_PER_CHUNK + i;
+                let (cells, appended) = row.split_at_mut(width);
+                for (cell, column) in cells.iter_mut().zip(columns) {
+                    *cell = F::from_miden_felt(padded_cell(column, row_idx, height));
+                }
+                let (limbs, is_padding) = appended.split_at_mut(layout::RANGE_LIMBS_WIDTH);
+                for (cell, limb) in limbs
+                    .iter_mut()
+                    .zip(range_limb_cells(columns, row_idx, height))
+                {
+                    *cell = limb;
+                }
+                is_padding[0] = is_padding_cell(row_idx, height);
+            }
+        });
+
+    Ok(RowMajorMatrix::new(data, out_width))
+}
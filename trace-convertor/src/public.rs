@@ -0,0 +1,52 @@
+//! Stack inputs and outputs exposed as public values of a proof.
+
+use alloc::vec::Vec;
+
+use miden_core::stack::MIN_STACK_DEPTH;
+use miden_core::Felt;
+use miden_processor::ExecutionTrace;
+
+use crate::MidenCompatibleField;
+
+/// The initial and final operand stack a proof of a Miden execution is bound to
+///
+/// Both hold the top [`MIN_STACK_DEPTH`] elements, top of the stack first.
+/// [`MidenProcessorAir`](crate::MidenProcessorAir) asserts the inputs on the first row
+/// and the outputs on the last real row (the last row before the padding selector is
+/// set) of the stack top columns when `prove` and `verify` are given [`Self::to_vec`]
+/// as public values; with no public values neither is checked.
+///
+/// Miden follows the last execution step with [`ExecutionTrace::NUM_RAND_ROWS`] random
+/// rows, which the converter keeps as real rows, so a converted Miden trace only
+/// satisfies the output constraint once its last real row carries the final stack.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MidenPublicValues {
+    /// Stack before the first operation
+    pub stack_inputs: [Felt; MIN_STACK_DEPTH],
+    /// Stack after the last operation
+    pub stack_outputs: [Felt; MIN_STACK_DEPTH],
+}
+
+impl MidenPublicValues {
+    /// Number of public values: the inputs followed by the outputs
+    pub const LEN: usize = 2 * MIN_STACK_DEPTH;
+
+    /// The stack a trace started from and the outputs its execution produced
+    pub fn from_trace(miden_trace: &ExecutionTrace) -> Self {
+        let mut stack_outputs = [Felt::default(); MIN_STACK_DEPTH];
+        stack_outputs.copy_from_slice(miden_trace.stack_outputs().stack_truncated(MIN_STACK_DEPTH));
+        Self {
+            stack_inputs: *miden_trace.init_stack_state(),
+            stack_outputs,
+        }
+    }
+
+    /// The public values to prove and verify with, inputs first
+    pub fn to_vec<F: MidenCompatibleField>(&self) -> Vec<F> {
+        self.stack_inputs
+            .iter()
+            .chain(&self.stack_outputs)
+            .map(|&felt| F::from_miden_felt(felt))
+            .collect()
+    }
+}
@@ -0,0 +1,84 @@
+//! Checking which constraints of the AIR a concrete trace satisfies.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use p3_field::PrimeCharacteristicRing;
+use p3_goldilocks::Goldilocks;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+use p3_uni_stark::{get_symbolic_constraints, Entry, SymbolicExpression};
+
+use crate::{MidenCompatibleField, MidenProcessorAir};
+
+impl MidenProcessorAir {
+    /// Check every constraint of the AIR against a converted trace, one by one
+    ///
+    /// Returns, in the order of [`Self::describe_constraints`], each constraint's
+    /// description, whether it holds on every row, and the first row where it does not.
+    /// Rows wrap around for next-row values as in Plonky3's own constraint check, so a
+    /// trace can be triaged constraint by constraint before it is proven.
+    pub fn constraint_report<F: MidenCompatibleField>(
+        &self,
+        matrix: &RowMajorMatrix<F>,
+    ) -> Vec<(String, bool, Option<usize>)> {
+        let height = matrix.height();
+        let rows: Vec<Vec<Goldilocks>> = (0..height)
+            .map(|r| {
+                matrix
+                    .row(r)
+                    .expect("row within trace")
+                    .into_iter()
+                    .map(|v| Goldilocks::from_u64(v.as_canonical_u64()))
+                    .collect()
+            })
+            .collect();
+
+        let constraints = get_symbolic_constraints::<Goldilocks, _>(self, 0, 0);
+        self.describe_constraints()
+            .into_iter()
+            .zip(&constraints)
+            .map(|(description, constraint)| {
+                let first_violation = (0..height).find(|&r| {
+                    let rows = RowPair {
+                        current: &rows[r],
+                        next: &rows[(r + 1) % height],
+                        is_first_row: r == 0,
+                        is_last_row: r == height - 1,
+                    };
+                    rows.eval(constraint) != Goldilocks::ZERO
+                });
+                (description, first_violation.is_none(), first_violation)
+            })
+            .collect()
+    }
+}
+
+/// A row and its successor, with the row selectors they imply
+struct RowPair<'a> {
+    current: &'a [Goldilocks],
+    next: &'a [Goldilocks],
+    is_first_row: bool,
+    is_last_row: bool,
+}
+
+impl RowPair<'_> {
+    fn eval(&self, expr: &SymbolicExpression<Goldilocks>) -> Goldilocks {
+        let flag = |b: bool| if b { Goldilocks::ONE } else { Goldilocks::ZERO };
+        match expr {
+            SymbolicExpression::Variable(var) => match var.entry {
+                Entry::Main { offset: 0 } => self.current[var.index],
+                Entry::Main { offset: 1 } => self.next[var.index],
+                entry => unreachable!("MidenProcessorAir only reads main rows, got {entry:?}"),
+            },
+            SymbolicExpression::IsFirstRow => flag(self.is_first_row),
+            SymbolicExpression::IsLastRow => flag(self.is_last_row),
+            SymbolicExpression::IsTransition => flag(!self.is_last_row),
+            SymbolicExpression::Constant(c) => *c,
+            SymbolicExpression::Add { x, y, .. } => self.eval(x) + self.eval(y),
+            SymbolicExpression::Sub { x, y, .. } => self.eval(x) - self.eval(y),
+            SymbolicExpression::Neg { x, .. } => -self.eval(x),
+            SymbolicExpression::Mul { x, y, .. } => self.eval(x) * self.eval(y),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Saving and reloading the main trace, so constraint work does not re-run Miden.
+//!
+//! Miden's `ExecutionTrace` cannot be serialized or rebuilt outside the processor, so a
+//! [`SavedTrace`] keeps what the converter reads: the main-segment columns and the
+//! number of real rows.
+
+use std::path::Path;
+use std::string::ToString;
+use std::vec::Vec;
+
+use miden_core::utils::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+};
+use miden_core::Felt;
+use miden_processor::ExecutionTrace;
+use p3_matrix::dense::RowMajorMatrix;
+use winter_prover::Trace;
+
+use crate::{ConversionError, MidenCompatibleField, TraceConverter};
+
+/// Main-segment columns of a Miden execution trace, detached from the processor
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SavedTrace {
+    /// Number of real (unpadded) rows
+    height: usize,
+    columns: Vec<Vec<Felt>>,
+}
+
+impl SavedTrace {
+    /// Copy the main-segment columns of an execution trace
+    pub fn from_trace(miden_trace: &ExecutionTrace) -> Self {
+        let height = miden_trace.length();
+        let main_segment = miden_trace.main_segment();
+        let columns = (0..miden_trace.main_trace_width())
+            .map(|col_idx| main_segment.get_column(col_idx)[..height].to_vec())
+            .collect();
+        Self { height, columns }
+    }
+
+    /// Number of real (unpadded) rows
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of main-trace columns
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Convert like [`TraceConverter::convert`] on the original trace
+    pub fn convert<F: MidenCompatibleField>(&self) -> Result<RowMajorMatrix<F>, ConversionError> {
+        let columns: Vec<&[Felt]> = self.columns.iter().map(Vec::as_slice).collect();
+        TraceConverter::convert_segment(&columns, self.height)
+    }
+}
+
+impl Serializable for SavedTrace {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.height);
+        target.write_usize(self.columns.len());
+        for column in &self.columns {
+            target.write_many(column);
+        }
+    }
+}
+
+impl Deserializable for SavedTrace {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let height = source.read_usize()?;
+        let width = source.read_usize()?;
+        let columns = (0..width)
+            .map(|_| source.read_many(height))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { height, columns })
+    }
+}
+
+impl TraceConverter {
+    /// Write the main trace of `miden_trace` to `path` with Miden's serialization
+    ///
+    /// Reload it with [`Self::load_trace_bin`].
+    pub fn save_trace_bin(
+        miden_trace: &ExecutionTrace,
+        path: impl AsRef<Path>,
+    ) -> Result<(), ConversionError> {
+        let bytes = SavedTrace::from_trace(miden_trace).to_bytes();
+        std::fs::write(path, bytes).map_err(|e| ConversionError::TraceFile(e.to_string()))
+    }
+
+    /// Read a main trace written by [`Self::save_trace_bin`]
+    pub fn load_trace_bin(path: impl AsRef<Path>) -> Result<SavedTrace, ConversionError> {
+        let bytes = std::fs::read(path).map_err(|e| ConversionError::TraceFile(e.to_string()))?;
+        SavedTrace::read_from_bytes(&bytes).map_err(|e| ConversionError::TraceFile(e.to_string()))
+    }
+}
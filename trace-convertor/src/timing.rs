@@ -0,0 +1,41 @@
+//! Measuring what a conversion costs, separately from proving.
+
+use core::time::Duration;
+use std::time::Instant;
+
+use miden_processor::ExecutionTrace;
+use p3_matrix::dense::RowMajorMatrix;
+
+use crate::{ConversionError, MidenCompatibleField, TraceConverter, TraceStats};
+
+/// Dimensions and cost of one call to [`TraceConverter::convert_with_report`]
+#[derive(Debug)]
+pub struct ConversionReport {
+    pub stats: TraceStats,
+    /// Wall-clock time spent converting
+    pub elapsed: Duration,
+    /// Size of the converted matrix's values, `padded_height * width * size_of::<F>()`
+    pub bytes_allocated: usize,
+}
+
+impl TraceConverter {
+    /// Convert a Miden execution trace like [`Self::convert`] and report what it cost
+    pub fn convert_with_report<F: MidenCompatibleField>(
+        miden_trace: &ExecutionTrace,
+    ) -> Result<(RowMajorMatrix<F>, ConversionReport), ConversionError> {
+        let start = Instant::now();
+        let matrix = Self::convert::<F>(miden_trace)?;
+        let elapsed = start.elapsed();
+
+        let stats = Self::trace_stats(miden_trace);
+        let bytes_allocated = stats.padded_height * stats.width * size_of::<F>();
+        Ok((
+            matrix,
+            ConversionReport {
+                stats,
+                elapsed,
+                bytes_allocated,
+            },
+        ))
+    }
+}
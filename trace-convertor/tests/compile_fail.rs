@@ -0,0 +1,7 @@
+//! Conversions must not compile for fields other than Miden's Goldilocks prime.
+
+#[test]
+fn incompatible_fields_do_not_compile() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,46 @@
+//! Snapshot of every constraint `MidenProcessorAir` enforces over the full Miden width.
+//!
+//! Any change to the constraint set (a constraint added, removed, reordered or moved to
+//! another column) fails this test. After checking that the change is intended, refresh
+//! the snapshot with:
+//! ```bash
+//! UPDATE_SNAPSHOT=1 cargo test -p p3-trace-convertor --test constraint_manifest
+//! ```
+
+use miden_assembly::Assembler;
+use miden_processor::{execute, AdviceInputs, DefaultHost, ExecutionOptions, StackInputs};
+use p3_trace_convertor::MidenProcessorAir;
+
+const SNAPSHOT_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/snapshots/constraint_manifest.txt"
+);
+
+#[test]
+fn constraint_manifest_matches_snapshot() {
+    let program = Assembler::default()
+        .assemble_program("begin push.1 drop end")
+        .unwrap();
+    let trace = execute(
+        &program,
+        StackInputs::default(),
+        AdviceInputs::default(),
+        &mut DefaultHost::default(),
+        ExecutionOptions::default(),
+    )
+    .unwrap();
+
+    let mut manifest = MidenProcessorAir::new(&trace)
+        .describe_constraints()
+        .join("\n");
+    manifest.push('\n');
+
+    if std::env::var_os("UPDATE_SNAPSHOT").is_some() {
+        std::fs::write(SNAPSHOT_PATH, &manifest).unwrap();
+    }
+    let expected = std::fs::read_to_string(SNAPSHOT_PATH).unwrap();
+    assert_eq!(
+        manifest, expected,
+        "constraint manifest changed; rerun with UPDATE_SNAPSHOT=1 if intended"
+    );
+}
@@ -0,0 +1,25 @@
+// A 64-bit Montgomery field over 2^64 - 59 rather than the Goldilocks prime.
+
+use miden_processor::ExecutionTrace;
+use p3_monty_64::{MontyField64, MontyParameters64};
+use p3_trace_convertor::TraceConverter;
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+struct OtherPrime;
+
+impl MontyParameters64 for OtherPrime {
+    const PRIME: u64 = 0xffffffffffffffc5;
+    const MONTY_R: u64 = 0x3b;
+    const MONTY_R2: u64 = 0xd99;
+    const MONTY_INV: u64 = 0xcbeea4e1a08ad8f3;
+    const MONTY_ZERO: MontyField64<Self> = MontyField64::new_monty(0);
+    const MONTY_ONE: MontyField64<Self> = MontyField64::new_monty(0x3b);
+    const MONTY_TWO: MontyField64<Self> = MontyField64::new_monty(0x76);
+    const MONTY_NEG_ONE: MontyField64<Self> = MontyField64::new_monty(0xffffffffffffff8a);
+}
+
+fn convert(trace: &ExecutionTrace) {
+    let _ = TraceConverter::convert::<MontyField64<OtherPrime>>(trace);
+}
+
+fn main() {}
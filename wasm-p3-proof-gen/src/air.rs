@@ -1,118 +1,92 @@
-use ark_std::string::ToString;
-use ark_std::vec;
+use ark_std::format;
+use ark_std::string::String;
 use ark_std::vec::Vec;
-use p3_air::{Air, AirBuilder, BaseAir};
-use p3_field::PrimeCharacteristicRing;
+use p3_fib_air::{last_column, sequential_first_row};
 use p3_matrix::{Matrix, dense::RowMajorMatrix};
 
 use crate::{Val, console_log};
 
-#[derive(Clone)]
-pub struct FibLikeAir {
-    pub final_result: Val,
-    pub num_col: usize,
-}
+pub use p3_fib_air::{DEFAULT_EXPONENT, FibLikeAir};
 
-impl<F> BaseAir<F> for FibLikeAir {
-    fn width(&self) -> usize {
-        self.num_col
-    }
+/// Generate the trace one row at a time, starting from `x_i = i + 1`
+///
+/// Yields the same rows as [`generate_trace`] while only keeping the current row
+/// alive, so a 2^20-row trace never has to be held in memory by the generator.
+pub fn generate_trace_rows(num_steps: usize, num_col: usize) -> impl Iterator<Item = Vec<Val>> {
+    generate_trace_rows_with_exponent(num_steps, num_col, DEFAULT_EXPONENT)
 }
 
-impl<AB: AirBuilder> Air<AB> for FibLikeAir {
-    fn eval(&self, builder: &mut AB) {
-        let main = builder.main();
-        let local = main.row_slice(0).expect("Matrix is empty?");
-        let next = main.row_slice(1).expect("Matrix only has 1 row?");
-
-        // Get all local variables
-        let x1 = local[0].clone();
-
-        // Constraint: x_1^8 + x_2 + ... + x_{num_col-1} = x_num_col
-        let x1_pow8 = x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone()
-            * x1.clone();
-
-        let mut sum = x1_pow8;
+/// [`generate_trace_rows`] for a [`FibLikeAir`] raising `x_1` to `exponent`
+pub fn generate_trace_rows_with_exponent(
+    num_steps: usize,
+    num_col: usize,
+    exponent: u32,
+) -> impl Iterator<Item = Vec<Val>> {
+    p3_fib_air::trace_rows(sequential_first_row(num_col), num_steps, exponent)
+}
 
-        // Add x_2 through x_{num_col-1}
-        for i in 1..self.num_col - 1 {
-            sum = sum + local[i].clone();
+/// Check every `FibLikeAir` constraint on a trace, row by row
+///
+/// The main constraint is checked with [`DEFAULT_EXPONENT`], as used by
+/// [`generate_trace`].
+///
+/// Returns the first violating row and which constraint failed, so a bad trace can
+/// be reported before Plonky3 panics somewhere inside the prover.
+pub fn check_trace(trace: &RowMajorMatrix<Val>) -> Result<(), (usize, String)> {
+    let num_col = trace.width();
+    let rows: Vec<&[Val]> = trace.values.chunks(num_col).collect();
+
+    for (i, row) in rows.iter().enumerate() {
+        let expected = last_column(row, DEFAULT_EXPONENT);
+        if row[num_col - 1] != expected {
+            return Err((
+                i,
+                format!(
+                    "x_{} = {} but x_1^{} + x_2 + ... + x_{} = {}",
+                    num_col,
+                    row[num_col - 1],
+                    DEFAULT_EXPONENT,
+                    num_col - 1,
+                    expected
+                ),
+            ));
+        }
+        if let Some(next) = rows.get(i + 1)
+            && next[0] != row[num_col - 1]
+        {
+            return Err((
+                i + 1,
+                format!(
+                    "x_1 = {} but the previous row has x_{} = {}",
+                    next[0],
+                    num_col,
+                    row[num_col - 1]
+                ),
+            ));
         }
-
-        // Assert sum equals x_num_col (last column)
-        builder.assert_zero(sum - local[self.num_col - 1].clone());
-
-        // Transition constraint: next_x1 = current x_num_col
-        let next_x1 = next[0].clone();
-        builder
-            .when_transition()
-            .assert_eq(next_x1, local[self.num_col - 1].clone());
-
-        // No initial constraints needed - allowing random starting values
     }
+
+    Ok(())
 }
 
 pub fn generate_trace(num_steps: usize, num_col: usize) -> (RowMajorMatrix<Val>, Val) {
+    generate_trace_with_exponent(num_steps, num_col, DEFAULT_EXPONENT)
+}
+
+/// Trace satisfying a [`FibLikeAir`] whose main constraint raises `x_1` to `exponent`
+pub fn generate_trace_with_exponent(
+    num_steps: usize,
+    num_col: usize,
+    exponent: u32,
+) -> (RowMajorMatrix<Val>, Val) {
     console_log!(
         "Starting trace generation: {} steps, {} columns",
         num_steps,
         num_col
     );
 
-    assert!(num_steps.is_power_of_two());
-    assert!(num_col >= 2, "num_col must be at least 2");
-
-    let mut values = Vec::with_capacity(num_steps * num_col);
-
-    // Initialize first row: need to satisfy x_1^8 + x_2 + ... + x_{num_col-1} = x_num_col
-    let mut current_row = (0..num_col)
-        .map(|i| Val::from_u32((i + 1) as u32))
-        .collect::<Vec<_>>();
-
-    // Make the first row satisfy the constraint: x_1^8 + x_2 + ... + x_{num_col-1} = x_num_col
-    let x1_pow8 = current_row[0].exp_u64(8); // 1^8 = 1
-    let mut sum = x1_pow8;
-    for i in 1..num_col - 1 {
-        sum += current_row[i]; // Add x_2, x_3, ..., x_{num_col-1}
-    }
-    current_row[num_col - 1] = sum; // Set x_num_col = sum
-
-    for step in 0..num_steps {
-        // Add current row to trace
-        values.extend_from_slice(&current_row);
-
-        // Compute next row if not the last step
-        if step < num_steps - 1 {
-            let mut next_row = vec![Val::ZERO; num_col];
-
-            // x_1 of next row = x_num_col of current row
-            next_row[0] = current_row[num_col - 1];
-
-            // For columns 1 to num_col-2: set to 1 for simplicity
-            for i in 1..num_col - 1 {
-                next_row[i] = Val::ONE;
-            }
-
-            // x_num_col = x_1^8 + x_2 + ... + x_{num_col-1}
-            let x1_pow8 = next_row[0].exp_u64(8);
-            let mut sum = x1_pow8;
-            for i in 1..num_col - 1 {
-                sum += next_row[i];
-            }
-            next_row[num_col - 1] = sum;
-
-            current_row = next_row;
-        }
-    }
-
-    let final_result = values[values.len() - num_col]; // First element of last row
-    let trace = RowMajorMatrix::new(values, num_col);
+    let (trace, final_result) =
+        p3_fib_air::trace_from_first_row(sequential_first_row(num_col), num_steps, exponent);
     console_log!(
         "Trace generated with {} rows, {} cols",
         trace.height(),
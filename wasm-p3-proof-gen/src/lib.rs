@@ -1,5 +1,8 @@
 #![no_std]
 
+#[cfg(not(target_arch = "wasm32"))]
+extern crate std;
+
 mod air;
 pub use air::*;
 
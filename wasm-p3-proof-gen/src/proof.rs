@@ -1,27 +1,18 @@
-use ark_std::string::ToString;
 use ark_std::vec;
 use p3_blake3::Blake3;
 use p3_dft::Radix2DitParallel;
 use p3_fri::FriParameters;
-use p3_matrix::Matrix;
-use p3_uni_stark::{prove, verify};
+use p3_matrix::{Matrix, dense::RowMajorMatrix};
+use p3_uni_stark::{Proof, prove, verify};
 
 use crate::{
     Blake3ByteHash, Blake3ChallengeMmcs, Blake3Challenger, Blake3Compress, Blake3Config,
-    Blake3FieldHash, Blake3Pcs, Blake3ValMmcs, FibLikeAir, Val, console_log, generate_trace,
+    Blake3FieldHash, Blake3Pcs, Blake3ValMmcs, DEFAULT_EXPONENT, FibLikeAir, Val, check_trace,
+    console_log, generate_trace,
 };
 
-pub fn run_example_blake3(num_steps: usize, num_col: usize) {
-    console_log!(
-        "Generating proof for sum constraint (x1^8 + x2 + ... + x{} = x{}) with {} steps using Blake3",
-        num_col - 1,
-        num_col,
-        num_steps
-    );
-
-    let (trace, final_result) = generate_trace(num_steps, num_col);
-    console_log!("Trace size: {}x{}", trace.height(), trace.width());
-
+/// Blake3-based STARK configuration shared by the wasm examples
+fn blake3_config() -> Blake3Config {
     // Set up Blake3-based cryptography
     let byte_hash = Blake3ByteHash {};
     let blake3_hash = Blake3 {};
@@ -43,24 +34,106 @@ pub fn run_example_blake3(num_steps: usize, num_col: usize) {
     let pcs = Blake3Pcs::new(dft, val_mmcs, fri_params);
     let challenger = Blake3Challenger::from_hasher(vec![], byte_hash);
 
-    let config = Blake3Config::new(pcs, challenger);
-    let air = FibLikeAir {
-        final_result,
+    Blake3Config::new(pcs, challenger)
+}
+
+/// Run the Blake3 example, optionally checking the trace against `FibLikeAir` first
+///
+/// Returns the postcard-serialized proof size in bytes if the proof verified, so a
+/// caller timing this gets the size without proving a second time.
+pub fn run_example_blake3(num_steps: usize, num_col: usize, validate: bool) -> Option<usize> {
+    console_log!(
+        "Generating proof for sum constraint (x1^8 + x2 + ... + x{} = x{}) with {} steps using Blake3",
+        num_col - 1,
         num_col,
+        num_steps
+    );
+
+    let (trace, final_result) = generate_trace(num_steps, num_col);
+    console_log!("Trace size: {}x{}", trace.height(), trace.width());
+
+    let proof = prove_verified_blake3(trace, final_result, validate)?;
+    let size = serialized_size(&proof);
+    console_log!("Proof size: {} bytes", size);
+    Some(size)
+}
+
+/// Prove and verify `trace` with Blake3, returning whether the proof verified
+///
+/// `final_result` is the public value the proof binds to the last row's `x_1`.
+///
+/// With `validate`, the trace is checked with [`check_trace`] first and the first
+/// violation, or a `final_result` the trace does not end in, is logged instead of
+/// proving, so a bad trace does not end in a Plonky3 panic in the browser.
+pub fn prove_trace_blake3(trace: RowMajorMatrix<Val>, final_result: Val, validate: bool) -> bool {
+    prove_verified_blake3(trace, final_result, validate).is_some()
+}
+
+/// The proof of `trace` if it verified, see [`prove_trace_blake3`]
+fn prove_verified_blake3(
+    trace: RowMajorMatrix<Val>,
+    final_result: Val,
+    validate: bool,
+) -> Option<Proof<Blake3Config>> {
+    if validate {
+        if let Err((row, msg)) = check_trace(&trace) {
+            console_log!("Trace violates FibLikeAir at row {}: {}", row, msg);
+            return None;
+        }
+        let last_x1 = trace.values[trace.values.len() - trace.width()];
+        if last_x1 != final_result {
+            console_log!(
+                "Trace ends in x_1 = {} but the claimed final result is {}",
+                last_x1,
+                final_result
+            );
+            return None;
+        }
+        console_log!("Trace satisfies FibLikeAir");
+    }
+
+    let config = blake3_config();
+    let air = FibLikeAir {
+        num_col: trace.width(),
+        exponent: DEFAULT_EXPONENT,
     };
 
     console_log!("Starting proof generation");
 
-    let proof = prove(&config, &air, trace, &vec![]);
+    let proof = prove(&config, &air, trace, &vec![final_result]);
 
     console_log!("Starting proof verification");
-    match verify(&config, &air, &proof, &vec![]) {
+    match verify(&config, &air, &proof, &vec![final_result]) {
         Ok(()) => {
             console_log!("Proof verified successfully!");
+            Some(proof)
         }
         Err(e) => {
             console_log!("Proof verification failed: {:?}", e);
-            return;
+            None
         }
     }
 }
+
+/// Prove the Blake3 example and return the length of the postcard-serialized proof in bytes
+pub fn proof_size_blake3(num_steps: usize, num_col: usize) -> usize {
+    let (trace, final_result) = generate_trace(num_steps, num_col);
+
+    let config = blake3_config();
+    let air = FibLikeAir {
+        num_col,
+        exponent: DEFAULT_EXPONENT,
+    };
+
+    let proof: Proof<Blake3Config> = prove(&config, &air, trace, &vec![final_result]);
+    let size = serialized_size(&proof);
+    console_log!("Proof size: {} bytes", size);
+
+    size
+}
+
+fn serialized_size(proof: &Proof<Blake3Config>) -> usize {
+    postcard::to_allocvec(proof)
+        .expect("proof serialization failed")
+        .len()
+}
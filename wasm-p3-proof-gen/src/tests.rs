@@ -5,12 +5,12 @@ use super::*;
 
 #[test]
 fn test_power8_gate_small_blake3() {
-    run_example_blake3(16, 3);
+    run_example_blake3(16, 3, true);
 }
 
 #[test]
 fn test_power8_gate_medium_blake3() {
-    run_example_blake3(256, 4);
+    run_example_blake3(256, 4, false);
 }
 
 #[test]
@@ -45,3 +45,44 @@ fn test_different_column_sizes() {
 
     console_log!("Different column size tests passed");
 }
+
+#[test]
+fn test_trace_rows_match_matrix() {
+    for (num_steps, num_col) in [(1, 2), (8, 3), (64, 5)] {
+        let (trace, _) = generate_trace(num_steps, num_col);
+        let rows: ark_std::vec::Vec<_> = generate_trace_rows(num_steps, num_col).collect();
+
+        assert_eq!(rows.len(), num_steps);
+        assert_eq!(rows.concat(), trace.values);
+    }
+}
+
+#[test]
+fn test_bench_p3_proof_size_is_positive() {
+    assert!(bench_p3_proof_size(16, 3) > 0);
+}
+
+#[test]
+fn test_run_example_returns_proof_size() {
+    let size = run_example_blake3_wasm(16, 3, true).expect("proof should verify");
+    assert_eq!(size, bench_p3_proof_size(16, 3));
+}
+
+#[test]
+fn test_validate_rejects_invalid_trace_before_proving() {
+    let (trace, final_result) = generate_trace(16, 3);
+    assert_eq!(check_trace(&trace), Ok(()));
+    assert!(prove_trace_blake3(trace.clone(), final_result, true));
+
+    // Break x_3 on row 1: the row constraint fails there, before the transition into row 2
+    let mut broken = trace;
+    broken.values[3 + 2] += Val::ONE;
+    assert_eq!(check_trace(&broken).unwrap_err().0, 1);
+    assert!(!prove_trace_blake3(broken, final_result, true));
+}
+
+#[test]
+fn test_validate_rejects_wrong_final_result() {
+    let (trace, final_result) = generate_trace(16, 3);
+    assert!(!prove_trace_blake3(trace, final_result + Val::ONE, true));
+}
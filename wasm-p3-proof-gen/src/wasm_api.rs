@@ -1,6 +1,6 @@
-use ark_std::string::ToString;
 use wasm_bindgen::prelude::*;
 
+#[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = console)]
@@ -13,12 +13,30 @@ extern "C" {
     fn performance_now() -> f64;
 }
 
+/// Native fallback so the crate's tests can run outside the browser
+#[cfg(not(target_arch = "wasm32"))]
+pub fn log(s: &str) {
+    std::println!("{}", s);
+}
+
 #[macro_export]
 macro_rules! console_log {
-    ($($t:tt)*) => ($crate::wasm_api::log(&format_args!($($t)*).to_string()))
+    ($($t:tt)*) => {{
+        use ark_std::string::ToString;
+        $crate::wasm_api::log(&format_args!($($t)*).to_string())
+    }};
+}
+
+/// Run the Blake3 example; with `validate` the trace is checked before proving
+///
+/// Returns the serialized proof size in bytes, or `undefined` if no proof verified.
+#[wasm_bindgen]
+pub fn run_example_blake3_wasm(num_steps: usize, num_col: usize, validate: bool) -> Option<u32> {
+    crate::proof::run_example_blake3(num_steps, num_col, validate).map(|size| size as u32)
 }
 
+/// Serialized proof size in bytes, to pair with the timing benches in a browser report
 #[wasm_bindgen]
-pub fn run_example_blake3_wasm(num_steps: usize, num_col: usize) {
-    crate::proof::run_example_blake3(num_steps, num_col);
+pub fn bench_p3_proof_size(num_steps: usize, num_col: usize) -> u32 {
+    crate::proof::proof_size_blake3(num_steps, num_col) as u32
 }